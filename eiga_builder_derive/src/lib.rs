@@ -6,7 +6,11 @@
 //! - The target struct has named fields.
 //! - Optional fields have their type written as `Option<...>`. The macro won't
 //! recognize the `Option` type in any other form, e.g., `std::option::Option`.
-//! - Optional fields represent query string parameters.
+//! - Optional fields represent query string parameters, unless they're marked
+//! with `#[body]`, in which case they represent request body parameters.
+//! - Doc comments on optional fields are appended to their setters' docs.
+//! - An optional field named `page` is a page number, which TMDB limits to
+//! between 1 and 500. Its setter's docs say so.
 //!
 //! # Example
 //!
@@ -52,7 +56,12 @@ use syn::{
     Ident, PathArguments, Type,
 };
 
-#[proc_macro_derive(Builder)]
+/// The docs appended to the setter of a `page` field.
+const PAGE_DOCS: &str = "The page must be between 1 and 500. Other pages \
+                         are rejected with `Error::Validation` before the \
+                         request is sent.";
+
+#[proc_macro_derive(Builder, attributes(body))]
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -119,11 +128,27 @@ fn setters(struct_data: &Data) -> TokenStream {
         // It's safe to unwrap here since optional_fields_iter iterates over
         // named fields only.
         let ident = field.ident.as_ref().unwrap();
-        let comment = format!("Sets the {} query string parameter.", ident);
+        let kind = if is_body(field) {
+            "body"
+        } else {
+            "query string"
+        };
+        let comment = format!("Sets the {} {} parameter.", ident, kind);
+        let docs = docs(field);
+        let page_docs = if ident == "page" {
+            quote! {
+                #[doc = ""]
+                #[doc = #PAGE_DOCS]
+            }
+        } else {
+            TokenStream::new()
+        };
         let ty = inner_type(&field.ty);
 
         quote! {
             #[doc = #comment]
+            #docs
+            #page_docs
             pub fn #ident(mut self, #ident: #ty) -> Self {
                 self.#ident = Some(#ident);
                 self
@@ -176,6 +201,29 @@ fn is_option(ty: &Type) -> bool {
     }
 }
 
+/// Returns a token stream of the doc comments of `field`, preceded by an
+/// empty line so they start a new paragraph of the setter's docs.
+fn docs(field: &Field) -> TokenStream {
+    let docs: Vec<_> = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .collect();
+    if docs.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        #[doc = ""]
+        #(#docs)*
+    }
+}
+
+/// Returns true if `field` is marked with `#[body]`.
+fn is_body(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path.is_ident("body"))
+}
+
 /// Returns an iterator over the named fields of a struct.
 ///
 /// # Panics
@@ -1,4 +1,3 @@
-use std::env;
 use std::error::Error;
 
 use serde::Deserialize;
@@ -12,8 +11,7 @@ struct MovieResult {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let token = env::var("TMDB_TOKEN")?;
-    let tmdb = Tmdb::new(token);
+    let tmdb = Tmdb::from_env()?;
 
     let search_movies_endpoint = search::Movies::new("Black Lizard");
 
@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::account::MediaType;
+use crate::{Body, Endpoint, Error};
+
+/// The account add to watchlist endpoint.
+///
+/// Set `watchlist` to `false` to remove the item instead.
+#[derive(Builder, Debug)]
+pub struct AddToWatchlist {
+    account_id: u64,
+    media_type: MediaType,
+    media_id: u64,
+    watchlist: bool,
+}
+
+impl Endpoint for AddToWatchlist {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}/watchlist", self.account_id).into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({
+            "media_type": self.media_type.as_str(),
+            "media_id": self.media_id,
+            "watchlist": self.watchlist,
+        });
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
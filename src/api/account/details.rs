@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The account details endpoint.
+#[derive(Builder, Debug)]
+pub struct Details {
+    account_id: u64,
+}
+
+impl Endpoint for Details {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}", self.account_id).into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
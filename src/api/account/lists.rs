@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{validation, Endpoint, Error, Pageable, Parameters};
+
+/// The account lists endpoint.
+#[derive(Builder, Debug)]
+pub struct Lists {
+    account_id: u64,
+    page: Option<u16>,
+}
+
+impl Endpoint for Lists {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}/lists", self.account_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("page", self.page);
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
+
+impl Pageable for Lists {
+    fn start_page(&self) -> Option<u16> {
+        self.page
+    }
+}
@@ -0,0 +1,18 @@
+/// The types of media that can be added to favorites or a watchlist.
+#[derive(Clone, Copy, Debug)]
+pub enum MediaType {
+    /// A movie.
+    Movie,
+    /// A TV show.
+    Tv,
+}
+
+impl MediaType {
+    /// Returns the media type as the string TMDB expects.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaType::Movie => "movie",
+            MediaType::Tv => "tv",
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Account API endpoints.
+
+mod add_favorite;
+mod add_to_watchlist;
+mod details;
+mod favorite_movies;
+mod favorite_tv;
+mod lists;
+mod media_type;
+mod rated_episodes;
+mod rated_movies;
+mod rated_tv;
+mod sort_by;
+mod watchlist_movies;
+mod watchlist_tv;
+
+pub use add_favorite::AddFavorite;
+pub use add_to_watchlist::AddToWatchlist;
+pub use details::Details;
+pub use favorite_movies::FavoriteMovies;
+pub use favorite_tv::FavoriteTv;
+pub use lists::Lists;
+pub use media_type::MediaType;
+pub use rated_episodes::RatedEpisodes;
+pub use rated_movies::RatedMovies;
+pub use rated_tv::RatedTv;
+pub use sort_by::SortBy;
+pub use watchlist_movies::WatchlistMovies;
+pub use watchlist_tv::WatchlistTv;
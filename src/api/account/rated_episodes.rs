@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::account::SortBy;
+use crate::{validation, Endpoint, Error, Language, Pageable, Parameters};
+
+/// The account rated TV episodes endpoint.
+#[derive(Builder, Debug)]
+pub struct RatedEpisodes {
+    account_id: u64,
+    language: Option<Language>,
+    page: Option<u16>,
+    sort_by: Option<SortBy>,
+}
+
+impl Endpoint for RatedEpisodes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}/rated/tv/episodes", self.account_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("language", self.language.as_ref());
+        parameters.push("page", self.page);
+        parameters.push("sort_by", self.sort_by.as_ref());
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        name == "language"
+    }
+}
+
+impl Pageable for RatedEpisodes {
+    fn start_page(&self) -> Option<u16> {
+        self.page
+    }
+}
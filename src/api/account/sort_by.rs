@@ -0,0 +1,21 @@
+use std::fmt;
+
+/// The sort orders for account results.
+#[derive(Clone, Copy, Debug)]
+pub enum SortBy {
+    /// Oldest first.
+    CreatedAtAsc,
+    /// Newest first.
+    CreatedAtDesc,
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sort_by = match self {
+            SortBy::CreatedAtAsc => "created_at.asc",
+            SortBy::CreatedAtDesc => "created_at.desc",
+        };
+
+        write!(f, "{}", sort_by)
+    }
+}
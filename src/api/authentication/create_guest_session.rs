@@ -0,0 +1,20 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The create guest session endpoint.
+#[derive(Builder, Debug)]
+pub struct CreateGuestSession {}
+
+impl Endpoint for CreateGuestSession {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication/guest_session/new".into()
+    }
+}
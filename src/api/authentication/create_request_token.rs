@@ -0,0 +1,20 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The create request token endpoint.
+#[derive(Builder, Debug)]
+pub struct CreateRequestToken {}
+
+impl Endpoint for CreateRequestToken {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication/token/new".into()
+    }
+}
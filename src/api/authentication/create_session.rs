@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{Body, Endpoint, Error};
+
+/// The create session endpoint.
+///
+/// The request token must be approved by the user before it can be
+/// exchanged for a session.
+#[derive(Builder, Debug)]
+pub struct CreateSession<'a> {
+    request_token: &'a str,
+}
+
+impl<'a> Endpoint for CreateSession<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication/session/new".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "request_token": self.request_token });
+
+        Body::json(&body).map(Some)
+    }
+}
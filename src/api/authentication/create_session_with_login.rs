@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{Body, Endpoint, Error};
+
+/// The create session with login endpoint.
+///
+/// This validates a request token with a user's username and password
+/// instead of sending the user to the TMDB website.
+#[derive(Builder)]
+pub struct CreateSessionWithLogin<'a> {
+    username: &'a str,
+    password: &'a str,
+    request_token: &'a str,
+}
+
+impl<'a> Endpoint for CreateSessionWithLogin<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication/token/validate_with_login".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({
+            "username": self.username,
+            "password": self.password,
+            "request_token": self.request_token,
+        });
+
+        Body::json(&body).map(Some)
+    }
+}
+
+// Implemented by hand so that the password doesn't end up in logs.
+impl<'a> fmt::Debug for CreateSessionWithLogin<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateSessionWithLogin")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("request_token", &self.request_token)
+            .finish()
+    }
+}
@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{Body, Endpoint, Error};
+
+/// The delete session endpoint.
+#[derive(Builder, Debug)]
+pub struct DeleteSession<'a> {
+    session_id: &'a str,
+}
+
+impl<'a> Endpoint for DeleteSession<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication/session".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "session_id": self.session_id });
+
+        Body::json(&body).map(Some)
+    }
+}
@@ -0,0 +1,15 @@
+//! Authentication API endpoints.
+
+mod create_guest_session;
+mod create_request_token;
+mod create_session;
+mod create_session_with_login;
+mod delete_session;
+mod validate;
+
+pub use create_guest_session::CreateGuestSession;
+pub use create_request_token::CreateRequestToken;
+pub use create_session::CreateSession;
+pub use create_session_with_login::CreateSessionWithLogin;
+pub use delete_session::DeleteSession;
+pub use validate::Validate;
@@ -0,0 +1,20 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The validate key endpoint.
+#[derive(Builder, Debug)]
+pub struct Validate {}
+
+impl Endpoint for Validate {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "authentication".into()
+    }
+}
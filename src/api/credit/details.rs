@@ -0,0 +1,24 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The credit details endpoint.
+///
+/// Credit IDs are strings, e.g., `"52fe4311c3a36847f8037ee9"`.
+#[derive(Builder, Debug)]
+pub struct Details<'a> {
+    id: &'a str,
+}
+
+impl<'a> Endpoint for Details<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("credit/{}", self.id).into()
+    }
+}
@@ -0,0 +1,5 @@
+//! Credits API endpoints.
+
+mod details;
+
+pub use details::Details;
@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{Body, Endpoint, Error};
+
+/// The add movie to list endpoint.
+#[derive(Builder, Debug)]
+pub struct AddMovie {
+    list_id: u64,
+    movie_id: u64,
+}
+
+impl Endpoint for AddMovie {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/add_item", self.list_id).into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "media_id": self.movie_id });
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{Endpoint, Parameters};
+
+/// The clear list endpoint.
+///
+/// This removes every item from the list. TMDB requires a confirmation
+/// parameter, which this endpoint always sets.
+#[derive(Builder, Debug)]
+pub struct Clear {
+    list_id: u64,
+}
+
+impl Endpoint for Clear {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/clear", self.list_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("confirm", Some(true));
+
+        parameters
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{Body, Endpoint, Error, Language};
+
+/// The create list endpoint.
+#[derive(Builder, Debug)]
+pub struct Create<'a> {
+    name: &'a str,
+    #[body]
+    description: Option<&'a str>,
+    #[body]
+    language: Option<Language>,
+}
+
+impl<'a> Endpoint for Create<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "list".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let mut body = json!({ "name": self.name });
+        if let Some(description) = self.description {
+            body["description"] = description.into();
+        }
+        if let Some(language) = &self.language {
+            body["language"] = language.to_string().into();
+        }
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The TMDB status code for an internal error.
+const INTERNAL_ERROR: u32 = 11;
+
+/// The delete list endpoint.
+#[derive(Builder, Debug)]
+pub struct Delete {
+    list_id: u64,
+}
+
+impl Endpoint for Delete {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}", self.list_id).into()
+    }
+
+    fn is_success(&self, status: u16, status_code: Option<u32>) -> bool {
+        // TMDB deletes the list but responds with an internal error anyway.
+        status == 500 && status_code == Some(INTERNAL_ERROR)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{
+    validation, Endpoint, Envelope, Error, Language, Pageable, Parameters,
+};
+
+/// The list details endpoint.
+#[derive(Builder, Debug)]
+pub struct Details {
+    list_id: u64,
+    language: Option<Language>,
+    page: Option<u16>,
+}
+
+impl Endpoint for Details {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}", self.list_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("language", self.language.as_ref());
+        parameters.push("page", self.page);
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        name == "language"
+    }
+}
+
+impl Pageable for Details {
+    fn start_page(&self) -> Option<u16> {
+        self.page
+    }
+
+    fn envelope(&self) -> Envelope {
+        Envelope::new().results("items")
+    }
+}
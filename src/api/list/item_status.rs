@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{Endpoint, Parameters};
+
+/// The list item status endpoint.
+#[derive(Builder, Debug)]
+pub struct ItemStatus {
+    list_id: u64,
+    movie_id: u64,
+}
+
+impl Endpoint for ItemStatus {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/item_status", self.list_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("movie_id", Some(self.movie_id.to_string()));
+
+        parameters
+    }
+}
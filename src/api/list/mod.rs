@@ -0,0 +1,17 @@
+//! List API endpoints.
+
+mod add_movie;
+mod clear;
+mod create;
+mod delete;
+mod details;
+mod item_status;
+mod remove_movie;
+
+pub use add_movie::AddMovie;
+pub use clear::Clear;
+pub use create::Create;
+pub use delete::Delete;
+pub use details::Details;
+pub use item_status::ItemStatus;
+pub use remove_movie::RemoveMovie;
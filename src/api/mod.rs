@@ -1,3 +1,10 @@
+pub mod account;
+pub mod authentication;
 pub mod configuration;
+pub mod credit;
+pub mod list;
 pub mod movie;
+pub mod review;
 pub mod search;
+pub mod tv;
+pub mod v4;
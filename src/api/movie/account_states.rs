@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The movie account states endpoint.
+#[derive(Builder, Debug)]
+pub struct AccountStates {
+    id: u64,
+}
+
+impl Endpoint for AccountStates {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("movie/{}/account_states", self.id).into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
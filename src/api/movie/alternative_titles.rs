@@ -21,7 +21,7 @@ impl Endpoint for AlternativeTitles {
         format!("movie/{}/alternative_titles", self.id).into()
     }
 
-    fn parameters(&self) -> Parameters {
+    fn parameters(&self) -> Parameters<'_> {
         let mut parameters = Parameters::new();
         parameters.push("country", self.country.as_ref());
 
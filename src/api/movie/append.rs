@@ -0,0 +1,61 @@
+/// The movie endpoints whose responses can be appended to a
+/// [`Details`](struct.Details.html) response.
+#[derive(Clone, Copy, Debug)]
+pub enum Append {
+    /// The account states of the movie.
+    AccountStates,
+    /// The alternative titles of the movie.
+    AlternativeTitles,
+    /// The changes to the movie.
+    Changes,
+    /// The credits of the movie.
+    Credits,
+    /// The external IDs of the movie.
+    ExternalIds,
+    /// The images of the movie.
+    Images,
+    /// The keywords of the movie.
+    Keywords,
+    /// The lists the movie belongs to.
+    Lists,
+    /// The recommendations for the movie.
+    Recommendations,
+    /// The release dates of the movie.
+    ReleaseDates,
+    /// The reviews of the movie.
+    Reviews,
+    /// The movies similar to the movie.
+    Similar,
+    /// The translations of the movie.
+    Translations,
+    /// The videos of the movie.
+    Videos,
+    /// The watch providers of the movie.
+    WatchProviders,
+}
+
+impl Append {
+    /// Returns the name TMDB uses for the appended response.
+    ///
+    /// This is also the key of the appended response in the combined
+    /// response.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Append::AccountStates => "account_states",
+            Append::AlternativeTitles => "alternative_titles",
+            Append::Changes => "changes",
+            Append::Credits => "credits",
+            Append::ExternalIds => "external_ids",
+            Append::Images => "images",
+            Append::Keywords => "keywords",
+            Append::Lists => "lists",
+            Append::Recommendations => "recommendations",
+            Append::ReleaseDates => "release_dates",
+            Append::Reviews => "reviews",
+            Append::Similar => "similar",
+            Append::Translations => "translations",
+            Append::Videos => "videos",
+            Append::WatchProviders => "watch/providers",
+        }
+    }
+}
@@ -21,10 +21,14 @@ impl Endpoint for Credits {
         format!("movie/{}/credits", self.id).into()
     }
 
-    fn parameters(&self) -> Parameters {
+    fn parameters(&self) -> Parameters<'_> {
         let mut parameters = Parameters::new();
         parameters.push("language", self.language.as_ref());
 
         parameters
     }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        name == "language"
+    }
 }
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The delete movie rating endpoint.
+#[derive(Builder, Debug)]
+pub struct DeleteRating {
+    id: u64,
+}
+
+impl Endpoint for DeleteRating {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("movie/{}/rating", self.id).into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
@@ -3,13 +3,57 @@ use std::borrow::Cow;
 use eiga_builder_derive::Builder;
 use http::Method;
 
-use crate::{Endpoint, Language, Parameters};
+use crate::movie::Append;
+use crate::{validation, Endpoint, Error, Language, Parameters};
 
 /// The movie details endpoint.
+///
+/// # Example
+///
+/// Set `append_to_response` to get the responses of other movie endpoints in
+/// the same request. Each appended response is a field of the combined
+/// response, named by [`Append::as_str`].
+///
+/// ```no_run
+/// use std::error::Error;
+///
+/// use serde::Deserialize;
+///
+/// use eiga::{movie, Client, Tmdb};
+///
+/// #[derive(Deserialize)]
+/// struct CastMember {
+///     name: String,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Credits {
+///     cast: Vec<CastMember>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct MovieDetails {
+///     title: String,
+///     credits: Credits,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let tmdb = Tmdb::new("<token>");
+///     let endpoint = movie::Details::new(45706)
+///         .append_to_response(vec![movie::Append::Credits]);
+///
+///     let movie_details: MovieDetails = tmdb.send(&endpoint)?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Append::as_str`]: enum.Append.html#method.as_str
 #[derive(Builder, Debug)]
 pub struct Details {
     id: u64,
     language: Option<Language>,
+    append_to_response: Option<Vec<Append>>,
 }
 
 impl Endpoint for Details {
@@ -21,10 +65,41 @@ impl Endpoint for Details {
         format!("movie/{}", self.id).into()
     }
 
-    fn parameters(&self) -> Parameters {
+    fn parameters(&self) -> Parameters<'_> {
         let mut parameters = Parameters::new();
         parameters.push("language", self.language.as_ref());
+        parameters.push(
+            "append_to_response",
+            self.append_to_response.as_ref().map(|append_to_response| {
+                append_to_response
+                    .iter()
+                    .map(Append::as_str)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        );
 
         parameters
     }
+
+    fn validate(&self) -> Result<(), Error> {
+        match &self.append_to_response {
+            Some(append_to_response) => {
+                validation::append_to_response(append_to_response.len())
+            }
+            None => Ok(()),
+        }
+    }
+
+    // TMDB only includes the account states if the request has a session.
+    fn requires_session(&self) -> bool {
+        self.append_to_response
+            .iter()
+            .flatten()
+            .any(|append| matches!(append, Append::AccountStates))
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        name == "language"
+    }
 }
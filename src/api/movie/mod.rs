@@ -1,9 +1,17 @@
 //! Movies API endpoints.
 
+mod account_states;
+mod add_rating;
 mod alternative_titles;
+mod append;
 mod credits;
+mod delete_rating;
 mod details;
 
+pub use account_states::AccountStates;
+pub use add_rating::AddRating;
 pub use alternative_titles::AlternativeTitles;
+pub use append::Append;
 pub use credits::Credits;
+pub use delete_rating::DeleteRating;
 pub use details::Details;
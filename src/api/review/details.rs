@@ -0,0 +1,24 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The review details endpoint.
+///
+/// Review IDs are strings, e.g., `"5488c29bc3a3686f4a00004a"`.
+#[derive(Builder, Debug)]
+pub struct Details<'a> {
+    id: &'a str,
+}
+
+impl<'a> Endpoint for Details<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("review/{}", self.id).into()
+    }
+}
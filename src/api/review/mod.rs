@@ -0,0 +1,5 @@
+//! Reviews API endpoints.
+
+mod details;
+
+pub use details::Details;
@@ -3,7 +3,9 @@ use std::borrow::Cow;
 use eiga_builder_derive::Builder;
 use http::Method;
 
-use crate::{Country, Endpoint, Language, Pageable, Parameters};
+use crate::{
+    validation, Country, Endpoint, Error, Language, Pageable, Parameters,
+};
 
 /// The search movies endpoint.
 #[derive(Builder, Debug)]
@@ -26,7 +28,7 @@ impl<'a> Endpoint for Movies<'a> {
         "search/movie".into()
     }
 
-    fn parameters(&self) -> Parameters {
+    fn parameters(&self) -> Parameters<'_> {
         let mut parameters = Parameters::new();
         parameters.push("query", Some(self.query));
         parameters.push("language", self.language.as_ref());
@@ -37,6 +39,14 @@ impl<'a> Endpoint for Movies<'a> {
         parameters.push("primary_release_year", self.primary_release_year);
         parameters
     }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        matches!(name, "language" | "include_adult" | "region")
+    }
 }
 
 impl<'a> Pageable for Movies<'a> {
@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{validation, Body, Endpoint, Error};
+
+/// The rate TV episode endpoint.
+///
+/// The rating must be between 0.5 and 10.0, inclusive, in steps of 0.5.
+#[derive(Builder, Debug)]
+pub struct AddRating {
+    tv_id: u64,
+    season_number: u16,
+    episode_number: u16,
+    value: f32,
+}
+
+impl Endpoint for AddRating {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!(
+            "tv/{}/season/{}/episode/{}/rating",
+            self.tv_id, self.season_number, self.episode_number
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "value": self.value });
+
+        Body::json(&body).map(Some)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::rating(self.value)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
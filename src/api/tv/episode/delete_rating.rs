@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::Endpoint;
+
+/// The delete TV episode rating endpoint.
+#[derive(Builder, Debug)]
+pub struct DeleteRating {
+    tv_id: u64,
+    season_number: u16,
+    episode_number: u16,
+}
+
+impl Endpoint for DeleteRating {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!(
+            "tv/{}/season/{}/episode/{}/rating",
+            self.tv_id, self.season_number, self.episode_number
+        )
+        .into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
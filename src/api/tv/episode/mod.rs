@@ -0,0 +1,7 @@
+//! TV episode API endpoints.
+
+mod add_rating;
+mod delete_rating;
+
+pub use add_rating::AddRating;
+pub use delete_rating::DeleteRating;
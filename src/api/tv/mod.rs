@@ -0,0 +1,9 @@
+//! TV API endpoints.
+
+pub mod episode;
+
+mod add_rating;
+mod delete_rating;
+
+pub use add_rating::AddRating;
+pub use delete_rating::DeleteRating;
@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{validation, ApiVersion, Endpoint, Error, Pageable, Parameters};
+
+/// The v4 account lists endpoint.
+#[derive(Builder, Debug)]
+pub struct Lists<'a> {
+    account_object_id: &'a str,
+    page: Option<u16>,
+}
+
+impl<'a> Endpoint for Lists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}/lists", self.account_object_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("page", self.page);
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Pageable for Lists<'a> {
+    fn start_page(&self) -> Option<u16> {
+        self.page
+    }
+}
@@ -0,0 +1,21 @@
+//! v4 account API endpoints.
+
+mod favorite_movies;
+mod favorite_tv;
+mod lists;
+mod movie_recommendations;
+mod movie_watchlist;
+mod rated_movies;
+mod rated_tv;
+mod tv_recommendations;
+mod tv_watchlist;
+
+pub use favorite_movies::FavoriteMovies;
+pub use favorite_tv::FavoriteTv;
+pub use lists::Lists;
+pub use movie_recommendations::MovieRecommendations;
+pub use movie_watchlist::MovieWatchlist;
+pub use rated_movies::RatedMovies;
+pub use rated_tv::RatedTv;
+pub use tv_recommendations::TvRecommendations;
+pub use tv_watchlist::TvWatchlist;
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::account::SortBy;
+use crate::{validation, ApiVersion, Endpoint, Error, Pageable, Parameters};
+
+/// The v4 account movie recommendations endpoint.
+#[derive(Builder, Debug)]
+pub struct MovieRecommendations<'a> {
+    account_object_id: &'a str,
+    page: Option<u16>,
+    sort_by: Option<SortBy>,
+}
+
+impl<'a> Endpoint for MovieRecommendations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("account/{}/movie/recommendations", self.account_object_id)
+            .into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("page", self.page);
+        parameters.push("sort_by", self.sort_by.as_ref());
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validation::page(self.page)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Pageable for MovieRecommendations<'a> {
+    fn start_page(&self) -> Option<u16> {
+        self.page
+    }
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{ApiVersion, Body, Endpoint, Error};
+
+/// The v4 create access token endpoint.
+///
+/// The request token must be approved by the user before it can be
+/// exchanged for an access token.
+#[derive(Builder, Debug)]
+pub struct CreateAccessToken<'a> {
+    request_token: &'a str,
+}
+
+impl<'a> Endpoint for CreateAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "auth/access_token".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "request_token": self.request_token });
+
+        Body::json(&body).map(Some)
+    }
+}
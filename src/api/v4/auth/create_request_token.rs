@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{ApiVersion, Body, Endpoint, Error};
+
+/// The v4 create request token endpoint.
+#[derive(Builder, Debug)]
+pub struct CreateRequestToken<'a> {
+    #[body]
+    redirect_to: Option<&'a str>,
+}
+
+impl<'a> Endpoint for CreateRequestToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "auth/request_token".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = match self.redirect_to {
+            Some(redirect_to) => json!({ "redirect_to": redirect_to }),
+            None => json!({}),
+        };
+
+        Body::json(&body).map(Some)
+    }
+}
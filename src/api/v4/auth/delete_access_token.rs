@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{ApiVersion, Body, Endpoint, Error};
+
+/// The v4 delete access token endpoint.
+#[derive(Builder, Debug)]
+pub struct DeleteAccessToken<'a> {
+    access_token: &'a str,
+}
+
+impl<'a> Endpoint for DeleteAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "auth/access_token".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let body = json!({ "access_token": self.access_token });
+
+        Body::json(&body).map(Some)
+    }
+}
@@ -0,0 +1,9 @@
+//! v4 auth API endpoints.
+
+mod create_access_token;
+mod create_request_token;
+mod delete_access_token;
+
+pub use create_access_token::CreateAccessToken;
+pub use create_request_token::CreateRequestToken;
+pub use delete_access_token::DeleteAccessToken;
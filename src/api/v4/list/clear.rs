@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{ApiVersion, Endpoint};
+
+/// The v4 clear list endpoint.
+#[derive(Builder, Debug)]
+pub struct Clear {
+    list_id: u64,
+}
+
+impl Endpoint for Clear {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/clear", self.list_id).into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
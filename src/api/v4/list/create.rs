@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::{ApiVersion, Body, Country, Endpoint, Error, Language};
+
+/// The v4 create list endpoint.
+#[derive(Builder, Debug)]
+pub struct Create<'a> {
+    name: &'a str,
+    language: Language,
+    #[body]
+    description: Option<&'a str>,
+    #[body]
+    public: Option<bool>,
+    #[body]
+    country: Option<Country>,
+}
+
+impl<'a> Endpoint for Create<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "list".into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let mut body = json!({
+            "name": self.name,
+            "iso_639_1": self.language.to_string(),
+        });
+        if let Some(description) = self.description {
+            body["description"] = description.into();
+        }
+        if let Some(public) = self.public {
+            body["public"] = public.into();
+        }
+        if let Some(country) = &self.country {
+            body["iso_3166_1"] = country.to_string().into();
+        }
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
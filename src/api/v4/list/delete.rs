@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::{ApiVersion, Endpoint};
+
+/// The v4 delete list endpoint.
+#[derive(Builder, Debug)]
+pub struct Delete {
+    list_id: u64,
+}
+
+impl Endpoint for Delete {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}", self.list_id).into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
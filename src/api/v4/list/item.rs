@@ -0,0 +1,28 @@
+use eiga_builder_derive::Builder;
+use serde_json::{json, Value};
+
+use crate::account::MediaType;
+
+/// An item in a list.
+#[derive(Builder, Clone, Debug)]
+pub struct Item<'a> {
+    media_type: MediaType,
+    media_id: u64,
+    #[body]
+    comment: Option<&'a str>,
+}
+
+impl<'a> Item<'a> {
+    /// Returns the item as the JSON object TMDB expects.
+    pub(crate) fn to_json(&self) -> Value {
+        let mut item = json!({
+            "media_type": self.media_type.as_str(),
+            "media_id": self.media_id,
+        });
+        if let Some(comment) = self.comment {
+            item["comment"] = comment.into();
+        }
+
+        item
+    }
+}
@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+
+use crate::account::MediaType;
+use crate::{ApiVersion, Endpoint, Parameters};
+
+/// The v4 list item status endpoint.
+#[derive(Builder, Debug)]
+pub struct ItemStatus {
+    list_id: u64,
+    media_type: MediaType,
+    media_id: u64,
+}
+
+impl Endpoint for ItemStatus {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/item_status", self.list_id).into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        parameters.push("media_id", Some(self.media_id.to_string()));
+        parameters.push("media_type", Some(self.media_type.as_str()));
+
+        parameters
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
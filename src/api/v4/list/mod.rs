@@ -0,0 +1,25 @@
+//! v4 list API endpoints.
+
+mod add_items;
+mod clear;
+mod create;
+mod delete;
+mod details;
+mod item;
+mod item_status;
+mod remove_items;
+mod sort_by;
+mod update;
+mod update_items;
+
+pub use add_items::AddItems;
+pub use clear::Clear;
+pub use create::Create;
+pub use delete::Delete;
+pub use details::Details;
+pub use item::Item;
+pub use item_status::ItemStatus;
+pub use remove_items::RemoveItems;
+pub use sort_by::SortBy;
+pub use update::Update;
+pub use update_items::UpdateItems;
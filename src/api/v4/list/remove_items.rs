@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::{json, Value};
+
+use crate::v4::list::Item;
+use crate::{ApiVersion, Body, Endpoint, Error};
+
+/// The v4 remove items endpoint.
+#[derive(Builder, Debug)]
+pub struct RemoveItems<'a> {
+    list_id: u64,
+    items: Vec<Item<'a>>,
+}
+
+impl<'a> Endpoint for RemoveItems<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}/items", self.list_id).into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let items: Vec<Value> = self.items.iter().map(Item::to_json).collect();
+        let body = json!({ "items": items });
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
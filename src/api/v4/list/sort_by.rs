@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// The sort orders for list items.
+#[derive(Clone, Copy, Debug)]
+pub enum SortBy {
+    /// The order items were added in, oldest first.
+    OriginalOrderAsc,
+    /// The order items were added in, newest first.
+    OriginalOrderDesc,
+    /// Release date, oldest first.
+    ReleaseDateAsc,
+    /// Release date, newest first.
+    ReleaseDateDesc,
+    /// Title, A to Z.
+    TitleAsc,
+    /// Title, Z to A.
+    TitleDesc,
+    /// Average vote, lowest first.
+    VoteAverageAsc,
+    /// Average vote, highest first.
+    VoteAverageDesc,
+}
+
+impl SortBy {
+    /// Returns the sort order as the string TMDB expects.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortBy::OriginalOrderAsc => "original_order.asc",
+            SortBy::OriginalOrderDesc => "original_order.desc",
+            SortBy::ReleaseDateAsc => "release_date.asc",
+            SortBy::ReleaseDateDesc => "release_date.desc",
+            SortBy::TitleAsc => "title.asc",
+            SortBy::TitleDesc => "title.desc",
+            SortBy::VoteAverageAsc => "vote_average.asc",
+            SortBy::VoteAverageDesc => "vote_average.desc",
+        }
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
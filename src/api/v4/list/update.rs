@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use eiga_builder_derive::Builder;
+use http::Method;
+use serde_json::json;
+
+use crate::v4::list::SortBy;
+use crate::{ApiVersion, Body, Endpoint, Error};
+
+/// The v4 update list endpoint.
+#[derive(Builder, Debug)]
+pub struct Update<'a> {
+    list_id: u64,
+    #[body]
+    name: Option<&'a str>,
+    #[body]
+    description: Option<&'a str>,
+    #[body]
+    public: Option<bool>,
+    #[body]
+    sort_by: Option<SortBy>,
+}
+
+impl<'a> Endpoint for Update<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V4
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        format!("list/{}", self.list_id).into()
+    }
+
+    fn body(&self) -> Result<Option<Body>, Error> {
+        let mut body = json!({});
+        if let Some(name) = self.name {
+            body["name"] = name.into();
+        }
+        if let Some(description) = self.description {
+            body["description"] = description.into();
+        }
+        if let Some(public) = self.public {
+            body["public"] = public.into();
+        }
+        if let Some(sort_by) = &self.sort_by {
+            body["sort_by"] = sort_by.as_str().into();
+        }
+
+        Body::json(&body).map(Some)
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
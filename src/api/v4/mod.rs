@@ -0,0 +1,7 @@
+//! TMDB API v4 endpoints.
+//!
+//! Clients send requests for these endpoints to the v4 base URL.
+
+pub mod account;
+pub mod auth;
+pub mod list;
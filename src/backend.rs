@@ -0,0 +1,196 @@
+use std::fmt;
+use std::io;
+
+use http::Method;
+use url::Url;
+
+use crate::Error;
+
+/// An HTTP response returned by an [`HttpBackend`].
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Constructs a new `HttpResponse` from the given status, headers, and
+    /// body.
+    pub fn new(
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    ) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the headers of the response.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of the given header, if the response has it.
+    ///
+    /// Header names are case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/// An HTTP response with a body that's read as it's received, returned by
+/// [`HttpBackend::send_streaming`].
+pub struct HttpStream {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Box<dyn io::Read + Send>,
+}
+
+impl HttpStream {
+    /// Constructs a new `HttpStream` from the given status, headers, and
+    /// body reader.
+    pub fn new<R>(
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: R,
+    ) -> HttpStream
+    where
+        R: io::Read + Send + 'static,
+    {
+        HttpStream {
+            status,
+            headers,
+            body: Box::new(body),
+        }
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the headers of the response.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of the given header, if the response has it.
+    ///
+    /// Header names are case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns a reader of the body of the response.
+    pub fn into_body(self) -> Box<dyn io::Read + Send> {
+        self.body
+    }
+}
+
+impl fmt::Debug for HttpStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpStream")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<HttpResponse> for HttpStream {
+    fn from(response: HttpResponse) -> HttpStream {
+        HttpStream::new(
+            response.status,
+            response.headers,
+            io::Cursor::new(response.body),
+        )
+    }
+}
+
+/// A trait for sending HTTP requests.
+///
+/// [`Tmdb`] sends its requests with an HTTP backend. The default backend,
+/// [`UreqBackend`], uses `ureq` and is available with the `ureq` feature.
+/// Implement this trait to use another HTTP client, and set it with
+/// `TmdbBuilder::backend`. [`Tmdb`] still takes care of authorization and
+/// of mapping TMDB's error responses to [`Error`]s.
+///
+/// # Example
+///
+/// ```
+/// use eiga::{Error, HttpBackend, HttpResponse};
+/// use http::Method;
+/// use url::Url;
+///
+/// #[derive(Debug)]
+/// struct NotFound;
+///
+/// impl HttpBackend for NotFound {
+///     fn send(
+///         &self,
+///         _method: &Method,
+///         _url: &Url,
+///         _headers: &[(&str, &str)],
+///         _body: Option<&[u8]>,
+///     ) -> Result<HttpResponse, Error> {
+///         let body = br#"{"status_code":34,"status_message":"Not found."}"#;
+///
+///         Ok(HttpResponse::new(404, Vec::new(), body.to_vec()))
+///     }
+/// }
+/// ```
+///
+/// [`Tmdb`]: crate::Tmdb
+/// [`UreqBackend`]: crate::UreqBackend
+pub trait HttpBackend: fmt::Debug + Send + Sync {
+    /// Sends a request and returns the response, whatever its status.
+    ///
+    /// Only return an error if there's no response, for example if the
+    /// connection failed. Wrap errors from other HTTP clients in
+    /// [`Error::Backend`].
+    fn send(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<HttpResponse, Error>;
+
+    /// Sends a request and returns the response, whatever its status, with
+    /// a body that's read as it's received.
+    ///
+    /// [`Tmdb::download_image`] uses this so that images aren't held in
+    /// memory. The default implementation reads the whole body with
+    /// [`HttpBackend::send`], so override it if the HTTP client can stream
+    /// responses.
+    ///
+    /// [`Tmdb::download_image`]: crate::Tmdb::download_image
+    fn send_streaming(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<HttpStream, Error> {
+        self.send(method, url, headers, body).map(HttpStream::from)
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Mutex;
+use std::thread;
+
+use serde::de::DeserializeOwned;
+
+use crate::{Client, Endpoint, Error};
+
+/// The number of requests a batch sends at once by default.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A batch of requests to send with bounded concurrency.
+///
+/// Construct one with [`Client::send_many`]. The batch sends its requests
+/// from a pool of threads, with at most [`SendMany::concurrency`] requests in
+/// flight at once. Endpoints are taken from the iterator as threads become
+/// free, so the iterator can be long or lazy.
+///
+/// A failed request doesn't stop the batch. Each endpoint gets its own
+/// result. A [`Tmdb`] sends the requests within its rate limit, if it has
+/// one, however high the concurrency.
+///
+/// # Example
+///
+/// ```no_run
+/// use eiga::{movie, Client, Tmdb};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct MovieDetails {
+///     title: String,
+/// }
+///
+/// let tmdb = Tmdb::new("<token>");
+/// let endpoints = [45706, 115572, 11645].map(movie::Details::new);
+///
+/// let results: Vec<Result<MovieDetails, _>> =
+///     tmdb.send_many(endpoints).concurrency(8).in_order();
+/// ```
+///
+/// [`Tmdb`]: crate::Tmdb
+#[derive(Debug)]
+pub struct SendMany<'a, C, I>
+where
+    C: ?Sized,
+{
+    client: &'a C,
+    endpoints: I,
+    concurrency: usize,
+}
+
+impl<'a, C, I> SendMany<'a, C, I>
+where
+    C: Client + ?Sized,
+    I: Iterator,
+    I::Item: Endpoint,
+{
+    pub(crate) fn new(client: &'a C, endpoints: I) -> SendMany<'a, C, I> {
+        SendMany {
+            client,
+            endpoints,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the maximum number of requests in flight at once. The default is
+    /// 4.
+    ///
+    /// A `concurrency` of 0 is treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+
+        self
+    }
+
+    /// Sends the requests and returns their results in the order of the
+    /// endpoints.
+    pub fn in_order<D>(self) -> Vec<Result<D, Error>>
+    where
+        C: Sync,
+        I: Send,
+        I::Item: Send,
+        D: DeserializeOwned + Send,
+    {
+        let mut results = Vec::new();
+        self.completed(|completed| {
+            for (index, result) in completed {
+                if index >= results.len() {
+                    results.resize_with(index + 1, || None);
+                }
+                results[index] = Some(result);
+            }
+        });
+
+        results
+            .into_iter()
+            .map(|result| result.expect("every endpoint has a result"))
+            .collect()
+    }
+
+    /// Sends the requests and calls `f` with an iterator over their results
+    /// in the order they complete. Returns what `f` returns.
+    ///
+    /// Each result is paired with the index of its endpoint. If `f` drops the
+    /// iterator before it's exhausted, the batch stops after the requests in
+    /// flight.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eiga::{movie, Client, Tmdb};
+    /// use serde_json::Value;
+    ///
+    /// let tmdb = Tmdb::new("<token>");
+    /// let endpoints = [45706, 115572, 11645].map(movie::Details::new);
+    ///
+    /// tmdb.send_many(endpoints).completed(|completed| {
+    ///     for (index, result) in completed {
+    ///         let result: Result<Value, _> = result;
+    ///         println!("{}: {}", index, result.is_ok());
+    ///     }
+    /// });
+    /// ```
+    pub fn completed<D, F, R>(self, f: F) -> R
+    where
+        C: Sync,
+        I: Send,
+        I::Item: Send,
+        D: DeserializeOwned + Send,
+        F: FnOnce(Completed<D>) -> R,
+    {
+        let client = self.client;
+        let endpoints = Mutex::new(self.endpoints.enumerate());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..self.concurrency {
+                let endpoints = &endpoints;
+                let sender = sender.clone();
+                scope.spawn(move || loop {
+                    let next = endpoints
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner())
+                        .next();
+                    let (index, endpoint) = match next {
+                        Some(next) => next,
+                        None => break,
+                    };
+
+                    let result = client.send(&endpoint);
+                    // Stop once the results aren't wanted anymore.
+                    if sender.send((index, result)).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(sender);
+
+            f(Completed { receiver })
+        })
+    }
+}
+
+/// An iterator over the results of a batch in the order they complete.
+///
+/// See [`SendMany::completed`].
+#[derive(Debug)]
+pub struct Completed<D> {
+    receiver: Receiver<(usize, Result<D, Error>)>,
+}
+
+impl<D> Iterator for Completed<D> {
+    type Item = (usize, Result<D, Error>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
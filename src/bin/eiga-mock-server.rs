@@ -0,0 +1,64 @@
+//! A local HTTP server that stands in for the TMDB API.
+//!
+//! ```text
+//! eiga-mock-server <FIXTURES> [--addr <ADDR>] [--token <TOKEN>] [--rate-limit <N>]
+//! ```
+//!
+//! See `eiga::testing::FixtureServer` for how fixtures are laid out.
+
+use std::env;
+use std::error::Error;
+use std::net::TcpListener;
+use std::process;
+
+use eiga::testing::FixtureServer;
+
+const USAGE: &str = "usage: eiga-mock-server <FIXTURES> [--addr <ADDR>] \
+                     [--token <TOKEN>] [--rate-limit <N>]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let mut dir = None;
+    let mut addr = "127.0.0.1:8080".to_owned();
+    let mut token = None;
+    let mut rate_limit = None;
+
+    while let Some(arg) = args.next() {
+        let mut value =
+            || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--addr" => addr = value()?,
+            "--token" => token = Some(value()?),
+            "--rate-limit" => rate_limit = Some(value()?.parse()?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if dir.is_none() && !arg.starts_with('-') => dir = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let dir = dir.ok_or("missing the fixtures directory")?;
+    let mut server = FixtureServer::new(dir);
+    if let Some(token) = token {
+        server = server.token(token);
+    }
+    if let Some(rate_limit) = rate_limit {
+        server = server.rate_limit(rate_limit);
+    }
+
+    let listener = TcpListener::bind(&addr)?;
+    println!("listening on http://{}/3/", listener.local_addr()?);
+    server.serve(&listener)?;
+
+    Ok(())
+}
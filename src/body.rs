@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Error;
+
+const JSON_CONTENT_TYPE: &str = "application/json;charset=utf-8";
+
+/// A request body.
+///
+/// # Example
+///
+/// ```
+/// use eiga::Body;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Rating {
+///     value: f32,
+/// }
+///
+/// let body = Body::json(&Rating { value: 8.5 }).unwrap();
+///
+/// assert_eq!("application/json;charset=utf-8", body.content_type());
+/// assert_eq!(br#"{"value":8.5}"#, body.as_bytes());
+/// ```
+#[derive(Debug)]
+pub struct Body {
+    content_type: &'static str,
+    bytes: Vec<u8>,
+}
+
+impl Body {
+    /// Constructs a new [`Body`] from the given content type and bytes.
+    pub fn new(content_type: &'static str, bytes: Vec<u8>) -> Body {
+        Body {
+            content_type,
+            bytes,
+        }
+    }
+
+    /// Constructs a new JSON [`Body`] by serializing the given value.
+    pub fn json<T>(value: &T) -> Result<Body, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let bytes = serde_json::to_vec(value)?;
+
+        Ok(Body::new(JSON_CONTENT_TYPE, bytes))
+    }
+
+    /// Returns the content type of the body.
+    pub fn content_type(&self) -> &str {
+        self.content_type
+    }
+
+    /// Returns the body as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Returns `body` as a string, with the values of the given top-level JSON
+/// fields replaced by their placeholders.
+///
+/// Bodies without any of the fields are returned as they are.
+pub(crate) fn redact<'a>(
+    body: &'a [u8],
+    fields: &[(&str, &str)],
+) -> Cow<'a, str> {
+    let body = String::from_utf8_lossy(body);
+
+    let mut value: Value = match serde_json::from_str(&body) {
+        Ok(Value::Object(object)) => Value::Object(object),
+        _ => return body,
+    };
+    let mut redacted = false;
+    for (field, placeholder) in fields {
+        if let Some(credential) = value.get_mut(*field) {
+            *credential = Value::from(*placeholder);
+            redacted = true;
+        }
+    }
+
+    if redacted {
+        value.to_string().into()
+    } else {
+        body
+    }
+}
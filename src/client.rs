@@ -1,6 +1,6 @@
 use serde::de::DeserializeOwned;
 
-use crate::{Endpoint, Error, PageIter, Pageable};
+use crate::{Endpoint, Error, PageIter, Pageable, SendMany};
 
 /// A trait for sending requests to endpoints.
 ///
@@ -44,4 +44,16 @@ pub trait Client {
     where
         E: Pageable,
         D: DeserializeOwned;
+
+    /// Returns a batch that sends requests to the given endpoints with
+    /// bounded concurrency.
+    ///
+    /// See [`SendMany`].
+    fn send_many<I>(&self, endpoints: I) -> SendMany<'_, Self, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Endpoint,
+    {
+        SendMany::new(self, endpoints.into_iter())
+    }
 }
@@ -0,0 +1,271 @@
+use std::env;
+#[cfg(feature = "config")]
+use std::fs;
+#[cfg(feature = "config")]
+use std::path::Path;
+use std::str::FromStr;
+#[cfg(feature = "ureq")]
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::{Error, Tmdb, TmdbBuilder};
+
+/// The prefix of the environment variables that configure a client.
+const ENV_PREFIX: &str = "TMDB_";
+
+/// The settings of a client, from the environment or a config file.
+///
+/// Timeouts are in seconds, and the rate limit is in requests per second.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    token: Option<String>,
+    base_url: Option<String>,
+    base_url_v4: Option<String>,
+    session_id: Option<String>,
+    access_token: Option<String>,
+    timeout: Option<f64>,
+    timeout_connect: Option<f64>,
+    timeout_read: Option<f64>,
+    proxy: Option<String>,
+    user_agent: Option<String>,
+    language: Option<String>,
+    region: Option<String>,
+    include_adult: Option<bool>,
+    watch_region: Option<String>,
+    retry: Option<u32>,
+    rate_limit: Option<u32>,
+}
+
+impl Config {
+    /// Reads the settings from `TMDB_*` environment variables.
+    pub(crate) fn from_env() -> Result<Config, Error> {
+        Ok(Config {
+            token: var("token")?,
+            base_url: var("base_url")?,
+            base_url_v4: var("base_url_v4")?,
+            session_id: var("session_id")?,
+            access_token: var("access_token")?,
+            timeout: seconds_var("timeout")?,
+            timeout_connect: seconds_var("timeout_connect")?,
+            timeout_read: seconds_var("timeout_read")?,
+            proxy: var("proxy")?,
+            user_agent: var("user_agent")?,
+            language: var("language")?,
+            region: var("region")?,
+            include_adult: bool_var("include_adult")?,
+            watch_region: var("watch_region")?,
+            retry: integer_var("retry")?,
+            rate_limit: integer_var("rate_limit")?,
+        })
+    }
+
+    /// Reads the settings from the TOML file at `path`.
+    #[cfg(feature = "config")]
+    pub(crate) fn from_file(path: &Path) -> Result<Config, Error> {
+        let contents = fs::read_to_string(path).map_err(|err| {
+            Error::Config(format!(
+                "failed to read {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+
+        toml::from_str(&contents).map_err(|err| {
+            Error::Config(format!(
+                "failed to parse {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+
+    /// Returns these settings with any settings in `other` taking
+    /// precedence.
+    #[cfg(feature = "config")]
+    pub(crate) fn merge(self, other: Config) -> Config {
+        Config {
+            token: other.token.or(self.token),
+            base_url: other.base_url.or(self.base_url),
+            base_url_v4: other.base_url_v4.or(self.base_url_v4),
+            session_id: other.session_id.or(self.session_id),
+            access_token: other.access_token.or(self.access_token),
+            timeout: other.timeout.or(self.timeout),
+            timeout_connect: other.timeout_connect.or(self.timeout_connect),
+            timeout_read: other.timeout_read.or(self.timeout_read),
+            proxy: other.proxy.or(self.proxy),
+            user_agent: other.user_agent.or(self.user_agent),
+            language: other.language.or(self.language),
+            region: other.region.or(self.region),
+            include_adult: other.include_adult.or(self.include_adult),
+            watch_region: other.watch_region.or(self.watch_region),
+            retry: other.retry.or(self.retry),
+            rate_limit: other.rate_limit.or(self.rate_limit),
+        }
+    }
+
+    /// Returns a `TmdbBuilder` configured with these settings.
+    pub(crate) fn into_builder(self) -> Result<TmdbBuilder, Error> {
+        let token = self.token.ok_or_else(|| {
+            Error::Config(format!(
+                "a token must be set with {}TOKEN or `token`",
+                ENV_PREFIX
+            ))
+        })?;
+
+        let mut builder = Tmdb::builder(token);
+        if let Some(base_url) = self.base_url {
+            builder.base_url(base_url);
+        }
+        if let Some(base_url_v4) = self.base_url_v4 {
+            builder.base_url_v4(base_url_v4);
+        }
+        if let Some(session_id) = self.session_id {
+            builder.session_id(session_id);
+        }
+        if let Some(access_token) = self.access_token {
+            builder.access_token(access_token);
+        }
+        if let Some(language) = self.language {
+            builder.language(parse(&language)?);
+        }
+        if let Some(region) = self.region {
+            builder.region(parse(&region)?);
+        }
+        if let Some(include_adult) = self.include_adult {
+            builder.include_adult(include_adult);
+        }
+        if let Some(watch_region) = self.watch_region {
+            builder.watch_region(parse(&watch_region)?);
+        }
+        if let Some(retry) = self.retry {
+            builder.retry(retry);
+        }
+        if let Some(rate_limit) = self.rate_limit {
+            builder.rate_limit(rate_limit);
+        }
+
+        #[cfg(feature = "ureq")]
+        {
+            if let Some(timeout) = self.timeout {
+                builder.timeout(duration("timeout", timeout)?);
+            }
+            if let Some(timeout_connect) = self.timeout_connect {
+                builder.timeout_connect(duration(
+                    "timeout_connect",
+                    timeout_connect,
+                )?);
+            }
+            if let Some(timeout_read) = self.timeout_read {
+                builder.timeout_read(duration("timeout_read", timeout_read)?);
+            }
+            if let Some(proxy) = self.proxy {
+                builder.proxy(proxy);
+            }
+            if let Some(user_agent) = self.user_agent {
+                builder.user_agent(user_agent);
+            }
+        }
+
+        #[cfg(not(feature = "ureq"))]
+        if self.timeout.is_some()
+            || self.timeout_connect.is_some()
+            || self.timeout_read.is_some()
+            || self.proxy.is_some()
+            || self.user_agent.is_some()
+        {
+            return Err(Error::Config(
+                "timeouts, proxy, and user agent require the `ureq` feature"
+                    .into(),
+            ));
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Returns the value of the environment variable for the given setting.
+fn var(setting: &str) -> Result<Option<String>, Error> {
+    let name = format!("{}{}", ENV_PREFIX, setting.to_uppercase());
+
+    match env::var(&name) {
+        Ok(value) => Ok(Some(value)),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(env::VarError::NotUnicode(_)) => {
+            Err(Error::Config(format!("{} must be valid unicode", name)))
+        }
+    }
+}
+
+/// Returns the number of seconds in the environment variable for the given
+/// setting.
+fn seconds_var(setting: &str) -> Result<Option<f64>, Error> {
+    var(setting)?
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                Error::Config(format!(
+                    "{}{} must be a number of seconds, got {:?}",
+                    ENV_PREFIX,
+                    setting.to_uppercase(),
+                    value
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Returns the boolean in the environment variable for the given setting.
+fn bool_var(setting: &str) -> Result<Option<bool>, Error> {
+    var(setting)?
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                Error::Config(format!(
+                    "{}{} must be `true` or `false`, got {:?}",
+                    ENV_PREFIX,
+                    setting.to_uppercase(),
+                    value
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Returns the non-negative integer in the environment variable for the
+/// given setting.
+fn integer_var(setting: &str) -> Result<Option<u32>, Error> {
+    var(setting)?
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                Error::Config(format!(
+                    "{}{} must be a non-negative integer, got {:?}",
+                    ENV_PREFIX,
+                    setting.to_uppercase(),
+                    value
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Parses a language or country setting.
+fn parse<T>(value: &str) -> Result<T, Error>
+where
+    T: FromStr<Err = Error>,
+{
+    value.parse().map_err(|err| match err {
+        Error::Validation(message) => Error::Config(message),
+        err => err,
+    })
+}
+
+/// Converts a number of seconds for the given setting to a `Duration`.
+#[cfg(feature = "ureq")]
+fn duration(setting: &str, seconds: f64) -> Result<Duration, Error> {
+    Duration::try_from_secs_f64(seconds).map_err(|_| {
+        Error::Config(format!(
+            "{} must be a non-negative number of seconds, got {}",
+            setting, seconds
+        ))
+    })
+}
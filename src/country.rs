@@ -1,4 +1,7 @@
 use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
 
 /// The countries used in TMDB.
 #[derive(Debug)]
@@ -752,3 +755,261 @@ impl fmt::Display for Country {
         }
     }
 }
+
+impl FromStr for Country {
+    type Err = Error;
+
+    /// Parses a country from its code. The code is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "AD" => Ok(Country::Ad),
+            "AE" => Ok(Country::Ae),
+            "AF" => Ok(Country::Af),
+            "AG" => Ok(Country::Ag),
+            "AI" => Ok(Country::Ai),
+            "AL" => Ok(Country::Al),
+            "AM" => Ok(Country::Am),
+            "AN" => Ok(Country::An),
+            "AO" => Ok(Country::Ao),
+            "AQ" => Ok(Country::Aq),
+            "AR" => Ok(Country::Ar),
+            "AS" => Ok(Country::As),
+            "AT" => Ok(Country::At),
+            "AU" => Ok(Country::Au),
+            "AW" => Ok(Country::Aw),
+            "AZ" => Ok(Country::Az),
+            "BA" => Ok(Country::Ba),
+            "BB" => Ok(Country::Bb),
+            "BD" => Ok(Country::Bd),
+            "BE" => Ok(Country::Be),
+            "BF" => Ok(Country::Bf),
+            "BG" => Ok(Country::Bg),
+            "BH" => Ok(Country::Bh),
+            "BI" => Ok(Country::Bi),
+            "BJ" => Ok(Country::Bj),
+            "BM" => Ok(Country::Bm),
+            "BN" => Ok(Country::Bn),
+            "BO" => Ok(Country::Bo),
+            "BR" => Ok(Country::Br),
+            "BS" => Ok(Country::Bs),
+            "BT" => Ok(Country::Bt),
+            "BV" => Ok(Country::Bv),
+            "BW" => Ok(Country::Bw),
+            "BY" => Ok(Country::By),
+            "BZ" => Ok(Country::Bz),
+            "CA" => Ok(Country::Ca),
+            "CC" => Ok(Country::Cc),
+            "CD" => Ok(Country::Cd),
+            "CF" => Ok(Country::Cf),
+            "CG" => Ok(Country::Cg),
+            "CH" => Ok(Country::Ch),
+            "CI" => Ok(Country::Ci),
+            "CK" => Ok(Country::Ck),
+            "CL" => Ok(Country::Cl),
+            "CM" => Ok(Country::Cm),
+            "CN" => Ok(Country::Cn),
+            "CO" => Ok(Country::Co),
+            "CR" => Ok(Country::Cr),
+            "CS" => Ok(Country::Cs),
+            "CU" => Ok(Country::Cu),
+            "CV" => Ok(Country::Cv),
+            "CX" => Ok(Country::Cx),
+            "CY" => Ok(Country::Cy),
+            "CZ" => Ok(Country::Cz),
+            "DE" => Ok(Country::De),
+            "DJ" => Ok(Country::Dj),
+            "DK" => Ok(Country::Dk),
+            "DM" => Ok(Country::Dm),
+            "DO" => Ok(Country::Do),
+            "DZ" => Ok(Country::Dz),
+            "EC" => Ok(Country::Ec),
+            "EE" => Ok(Country::Ee),
+            "EG" => Ok(Country::Eg),
+            "EH" => Ok(Country::Eh),
+            "ER" => Ok(Country::Er),
+            "ES" => Ok(Country::Es),
+            "ET" => Ok(Country::Et),
+            "FI" => Ok(Country::Fi),
+            "FJ" => Ok(Country::Fj),
+            "FK" => Ok(Country::Fk),
+            "FM" => Ok(Country::Fm),
+            "FO" => Ok(Country::Fo),
+            "FR" => Ok(Country::Fr),
+            "GA" => Ok(Country::Ga),
+            "GB" => Ok(Country::Gb),
+            "GD" => Ok(Country::Gd),
+            "GE" => Ok(Country::Ge),
+            "GF" => Ok(Country::Gf),
+            "GH" => Ok(Country::Gh),
+            "GI" => Ok(Country::Gi),
+            "GL" => Ok(Country::Gl),
+            "GM" => Ok(Country::Gm),
+            "GN" => Ok(Country::Gn),
+            "GP" => Ok(Country::Gp),
+            "GQ" => Ok(Country::Gq),
+            "GR" => Ok(Country::Gr),
+            "GS" => Ok(Country::Gs),
+            "GT" => Ok(Country::Gt),
+            "GU" => Ok(Country::Gu),
+            "GW" => Ok(Country::Gw),
+            "GY" => Ok(Country::Gy),
+            "HK" => Ok(Country::Hk),
+            "HM" => Ok(Country::Hm),
+            "HN" => Ok(Country::Hn),
+            "HR" => Ok(Country::Hr),
+            "HT" => Ok(Country::Ht),
+            "HU" => Ok(Country::Hu),
+            "ID" => Ok(Country::Id),
+            "IE" => Ok(Country::Ie),
+            "IL" => Ok(Country::Il),
+            "IN" => Ok(Country::In),
+            "IO" => Ok(Country::Io),
+            "IQ" => Ok(Country::Iq),
+            "IR" => Ok(Country::Ir),
+            "IS" => Ok(Country::Is),
+            "IT" => Ok(Country::It),
+            "JM" => Ok(Country::Jm),
+            "JO" => Ok(Country::Jo),
+            "JP" => Ok(Country::Jp),
+            "KE" => Ok(Country::Ke),
+            "KG" => Ok(Country::Kg),
+            "KH" => Ok(Country::Kh),
+            "KI" => Ok(Country::Ki),
+            "KM" => Ok(Country::Km),
+            "KN" => Ok(Country::Kn),
+            "KP" => Ok(Country::Kp),
+            "KR" => Ok(Country::Kr),
+            "KW" => Ok(Country::Kw),
+            "KY" => Ok(Country::Ky),
+            "KZ" => Ok(Country::Kz),
+            "LA" => Ok(Country::La),
+            "LB" => Ok(Country::Lb),
+            "LC" => Ok(Country::Lc),
+            "LI" => Ok(Country::Li),
+            "LK" => Ok(Country::Lk),
+            "LR" => Ok(Country::Lr),
+            "LS" => Ok(Country::Ls),
+            "LT" => Ok(Country::Lt),
+            "LU" => Ok(Country::Lu),
+            "LV" => Ok(Country::Lv),
+            "LY" => Ok(Country::Ly),
+            "MA" => Ok(Country::Ma),
+            "MC" => Ok(Country::Mc),
+            "MD" => Ok(Country::Md),
+            "ME" => Ok(Country::Me),
+            "MG" => Ok(Country::Mg),
+            "MH" => Ok(Country::Mh),
+            "MK" => Ok(Country::Mk),
+            "ML" => Ok(Country::Ml),
+            "MM" => Ok(Country::Mm),
+            "MN" => Ok(Country::Mn),
+            "MO" => Ok(Country::Mo),
+            "MP" => Ok(Country::Mp),
+            "MQ" => Ok(Country::Mq),
+            "MR" => Ok(Country::Mr),
+            "MS" => Ok(Country::Ms),
+            "MT" => Ok(Country::Mt),
+            "MU" => Ok(Country::Mu),
+            "MV" => Ok(Country::Mv),
+            "MW" => Ok(Country::Mw),
+            "MX" => Ok(Country::Mx),
+            "MY" => Ok(Country::My),
+            "MZ" => Ok(Country::Mz),
+            "NA" => Ok(Country::Na),
+            "NC" => Ok(Country::Nc),
+            "NE" => Ok(Country::Ne),
+            "NF" => Ok(Country::Nf),
+            "NG" => Ok(Country::Ng),
+            "NI" => Ok(Country::Ni),
+            "NL" => Ok(Country::Nl),
+            "NO" => Ok(Country::No),
+            "NP" => Ok(Country::Np),
+            "NR" => Ok(Country::Nr),
+            "NU" => Ok(Country::Nu),
+            "NZ" => Ok(Country::Nz),
+            "OM" => Ok(Country::Om),
+            "PA" => Ok(Country::Pa),
+            "PE" => Ok(Country::Pe),
+            "PF" => Ok(Country::Pf),
+            "PG" => Ok(Country::Pg),
+            "PH" => Ok(Country::Ph),
+            "PK" => Ok(Country::Pk),
+            "PL" => Ok(Country::Pl),
+            "PM" => Ok(Country::Pm),
+            "PN" => Ok(Country::Pn),
+            "PR" => Ok(Country::Pr),
+            "PS" => Ok(Country::Ps),
+            "PT" => Ok(Country::Pt),
+            "PW" => Ok(Country::Pw),
+            "PY" => Ok(Country::Py),
+            "QA" => Ok(Country::Qa),
+            "RE" => Ok(Country::Re),
+            "RO" => Ok(Country::Ro),
+            "RS" => Ok(Country::Rs),
+            "RU" => Ok(Country::Ru),
+            "RW" => Ok(Country::Rw),
+            "SA" => Ok(Country::Sa),
+            "SB" => Ok(Country::Sb),
+            "SC" => Ok(Country::Sc),
+            "SD" => Ok(Country::Sd),
+            "SE" => Ok(Country::Se),
+            "SG" => Ok(Country::Sg),
+            "SH" => Ok(Country::Sh),
+            "SI" => Ok(Country::Si),
+            "SJ" => Ok(Country::Sj),
+            "SK" => Ok(Country::Sk),
+            "SL" => Ok(Country::Sl),
+            "SM" => Ok(Country::Sm),
+            "SN" => Ok(Country::Sn),
+            "SO" => Ok(Country::So),
+            "SR" => Ok(Country::Sr),
+            "SS" => Ok(Country::Ss),
+            "ST" => Ok(Country::St),
+            "SU" => Ok(Country::Su),
+            "SV" => Ok(Country::Sv),
+            "SY" => Ok(Country::Sy),
+            "SZ" => Ok(Country::Sz),
+            "TC" => Ok(Country::Tc),
+            "TD" => Ok(Country::Td),
+            "TF" => Ok(Country::Tf),
+            "TG" => Ok(Country::Tg),
+            "TH" => Ok(Country::Th),
+            "TJ" => Ok(Country::Tj),
+            "TK" => Ok(Country::Tk),
+            "TL" => Ok(Country::Tl),
+            "TM" => Ok(Country::Tm),
+            "TN" => Ok(Country::Tn),
+            "TO" => Ok(Country::To),
+            "TR" => Ok(Country::Tr),
+            "TT" => Ok(Country::Tt),
+            "TV" => Ok(Country::Tv),
+            "TW" => Ok(Country::Tw),
+            "TZ" => Ok(Country::Tz),
+            "UA" => Ok(Country::Ua),
+            "UG" => Ok(Country::Ug),
+            "UM" => Ok(Country::Um),
+            "US" => Ok(Country::Us),
+            "UY" => Ok(Country::Uy),
+            "UZ" => Ok(Country::Uz),
+            "VA" => Ok(Country::Va),
+            "VC" => Ok(Country::Vc),
+            "VE" => Ok(Country::Ve),
+            "VG" => Ok(Country::Vg),
+            "VI" => Ok(Country::Vi),
+            "VN" => Ok(Country::Vn),
+            "VU" => Ok(Country::Vu),
+            "WF" => Ok(Country::Wf),
+            "WS" => Ok(Country::Ws),
+            "XC" => Ok(Country::Xc),
+            "XG" => Ok(Country::Xg),
+            "XK" => Ok(Country::Xk),
+            "YE" => Ok(Country::Ye),
+            "YT" => Ok(Country::Yt),
+            "YU" => Ok(Country::Yu),
+            "ZA" => Ok(Country::Za),
+            "ZM" => Ok(Country::Zm),
+            "ZW" => Ok(Country::Zw),
+            _ => Err(Error::Validation(format!("unknown country: {}", s))),
+        }
+    }
+}
@@ -1,8 +1,22 @@
 use std::borrow::Cow;
 
 use http::Method;
+use serde::{Deserialize, Serialize};
 
-use crate::Parameters;
+use crate::{Body, Error, Parameters};
+
+/// The query string parameters that clients have defaults for.
+pub(crate) const DEFAULT_PARAMETERS: [&str; 4] =
+    ["language", "region", "include_adult", "watch_region"];
+
+/// The versions of the TMDB API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiVersion {
+    /// Version 3.
+    V3,
+    /// Version 4.
+    V4,
+}
 
 /// A trait for endpoint objects.
 ///
@@ -12,13 +26,14 @@ use crate::Parameters;
 ///
 /// ```
 /// // For normal usage of eiga you don't need to pull `Endpoint` into scope.
-/// use eiga::{movie, Endpoint};
+/// use eiga::{movie, ApiVersion, Endpoint};
 ///
 /// let endpoint = movie::AlternativeTitles::new(34048);
 ///
+/// assert_eq!(ApiVersion::V3, endpoint.api_version());
 /// assert_eq!("GET", endpoint.method().as_str());
 /// assert_eq!("movie/34048/alternative_titles", endpoint.path());
-/// assert_eq!(None, endpoint.body());
+/// assert!(endpoint.body().unwrap().is_none());
 /// ```
 ///
 /// [`movie::AlternativeTitles`]: movie/struct.AlternativeTitles.html
@@ -26,16 +41,62 @@ pub trait Endpoint {
     /// Returns the HTTP method of this endpoint.
     fn method(&self) -> Method;
 
+    /// Returns the API version of this endpoint.
+    ///
+    /// Clients resolve the path against the base URL for this version.
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::V3
+    }
+
     /// Returns the path of this endpoint.
     fn path(&self) -> Cow<'static, str>;
 
     /// Returns the query string parameters of this endpoint.
-    fn parameters(&self) -> Parameters {
+    fn parameters(&self) -> Parameters<'_> {
         Parameters::new()
     }
 
     /// Returns the request body of this endpoint.
-    fn body(&self) -> Option<Vec<u8>> {
-        None
+    ///
+    /// Clients set the `Content-Type` header from the body's content type.
+    fn body(&self) -> Result<Option<Body>, Error> {
+        Ok(None)
+    }
+
+    /// Checks that this endpoint is valid.
+    ///
+    /// Clients call this before sending a request so that invalid endpoints
+    /// fail without a round trip to TMDB.
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Returns `true` if an error response from TMDB actually means the
+    /// request succeeded.
+    ///
+    /// A few TMDB endpoints respond with an error status even when they
+    /// succeed. `status` is the HTTP status and `status_code` is the TMDB
+    /// status code in the response body.
+    fn is_success(&self, _status: u16, _status_code: Option<u32>) -> bool {
+        false
+    }
+
+    /// Returns `true` if this endpoint acts on behalf of a user.
+    ///
+    /// Clients attach their session ID, if they have one, to requests for
+    /// these endpoints. For v4 endpoints, clients authorize the request with
+    /// their user access token instead.
+    fn requires_session(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if this endpoint has the query string parameter
+    /// `name` and clients should fill it in with their default.
+    ///
+    /// Clients only fill in parameters that the endpoint didn't set. The
+    /// parameters with client defaults are `language`, `region`,
+    /// `include_adult`, and `watch_region`.
+    fn accepts_default(&self, _name: &str) -> bool {
+        false
     }
 }
@@ -5,10 +5,16 @@ use std::io;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// Deserialization error.
-    #[error("failed to deserialize the TMDB response: {}", self)]
+    #[error("failed to deserialize the TMDB response: {}", .0)]
     Deserialize(#[from] io::Error),
+    /// Serialization error.
+    #[error("failed to serialize the request body: {}", .0)]
+    Serialize(#[from] serde_json::Error),
+    /// I/O error.
+    #[error("failed to read or write data: {}", .0)]
+    Io(io::Error),
     /// Invalid URL.
-    #[error("failed to parse a URL: {}", self)]
+    #[error("failed to parse a URL: {}", .0)]
     Url(#[from] url::ParseError),
     /// TMDB API error.
     #[error("TMDB responded with an unexpected status: {}", .message)]
@@ -18,7 +24,17 @@ pub enum Error {
         /// The error message.
         message: String,
     },
+    /// Configuration error.
+    #[error("the configuration is invalid: {}", .0)]
+    Config(String),
+    /// Validation error.
+    #[error("the request is invalid: {}", .0)]
+    Validation(String),
     /// Transport error.
-    #[error("failed to make the request or receive an response: {}", self)]
+    #[cfg(feature = "ureq")]
+    #[error("failed to make the request or receive an response: {}", .0)]
     Transport(#[from] ureq::Transport),
+    /// Error from a custom HTTP backend.
+    #[error("failed to make the request or receive an response: {}", .0)]
+    Backend(Box<dyn std::error::Error + Send + Sync>),
 }
@@ -0,0 +1,639 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::{configuration, Client, Error, Tmdb};
+
+/// The kinds of images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageKind {
+    /// Backdrop images.
+    Backdrop,
+    /// Logo images.
+    Logo,
+    /// Poster images.
+    Poster,
+    /// Profile images.
+    Profile,
+    /// Still images.
+    Still,
+}
+
+impl fmt::Display for ImageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ImageKind::Backdrop => "backdrop",
+            ImageKind::Logo => "logo",
+            ImageKind::Poster => "poster",
+            ImageKind::Profile => "profile",
+            ImageKind::Still => "still",
+        };
+
+        write!(f, "{}", kind)
+    }
+}
+
+/// A trait for the sizes of a kind of image.
+pub trait ImageSize: Copy + fmt::Display {
+    /// The kind of image this is a size of.
+    const KIND: ImageKind;
+}
+
+/// The sizes of poster images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PosterSize {
+    /// 92 pixels wide.
+    W92,
+    /// 154 pixels wide.
+    W154,
+    /// 185 pixels wide.
+    W185,
+    /// 342 pixels wide.
+    W342,
+    /// 500 pixels wide.
+    W500,
+    /// 780 pixels wide.
+    W780,
+    /// The original size.
+    Original,
+}
+
+impl PosterSize {
+    /// Returns the size as the string TMDB uses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PosterSize::W92 => "w92",
+            PosterSize::W154 => "w154",
+            PosterSize::W185 => "w185",
+            PosterSize::W342 => "w342",
+            PosterSize::W500 => "w500",
+            PosterSize::W780 => "w780",
+            PosterSize::Original => "original",
+        }
+    }
+}
+
+impl ImageSize for PosterSize {
+    const KIND: ImageKind = ImageKind::Poster;
+}
+
+impl fmt::Display for PosterSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The sizes of backdrop images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackdropSize {
+    /// 300 pixels wide.
+    W300,
+    /// 780 pixels wide.
+    W780,
+    /// 1280 pixels wide.
+    W1280,
+    /// The original size.
+    Original,
+}
+
+impl BackdropSize {
+    /// Returns the size as the string TMDB uses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackdropSize::W300 => "w300",
+            BackdropSize::W780 => "w780",
+            BackdropSize::W1280 => "w1280",
+            BackdropSize::Original => "original",
+        }
+    }
+}
+
+impl ImageSize for BackdropSize {
+    const KIND: ImageKind = ImageKind::Backdrop;
+}
+
+impl fmt::Display for BackdropSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The sizes of logo images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogoSize {
+    /// 45 pixels wide.
+    W45,
+    /// 92 pixels wide.
+    W92,
+    /// 154 pixels wide.
+    W154,
+    /// 185 pixels wide.
+    W185,
+    /// 300 pixels wide.
+    W300,
+    /// 500 pixels wide.
+    W500,
+    /// The original size.
+    Original,
+}
+
+impl LogoSize {
+    /// Returns the size as the string TMDB uses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogoSize::W45 => "w45",
+            LogoSize::W92 => "w92",
+            LogoSize::W154 => "w154",
+            LogoSize::W185 => "w185",
+            LogoSize::W300 => "w300",
+            LogoSize::W500 => "w500",
+            LogoSize::Original => "original",
+        }
+    }
+}
+
+impl ImageSize for LogoSize {
+    const KIND: ImageKind = ImageKind::Logo;
+}
+
+impl fmt::Display for LogoSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The sizes of profile images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileSize {
+    /// 45 pixels wide.
+    W45,
+    /// 185 pixels wide.
+    W185,
+    /// 632 pixels high.
+    H632,
+    /// The original size.
+    Original,
+}
+
+impl ProfileSize {
+    /// Returns the size as the string TMDB uses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileSize::W45 => "w45",
+            ProfileSize::W185 => "w185",
+            ProfileSize::H632 => "h632",
+            ProfileSize::Original => "original",
+        }
+    }
+}
+
+impl ImageSize for ProfileSize {
+    const KIND: ImageKind = ImageKind::Profile;
+}
+
+impl fmt::Display for ProfileSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The sizes of still images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StillSize {
+    /// 92 pixels wide.
+    W92,
+    /// 185 pixels wide.
+    W185,
+    /// 300 pixels wide.
+    W300,
+    /// The original size.
+    Original,
+}
+
+impl StillSize {
+    /// Returns the size as the string TMDB uses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StillSize::W92 => "w92",
+            StillSize::W185 => "w185",
+            StillSize::W300 => "w300",
+            StillSize::Original => "original",
+        }
+    }
+}
+
+impl ImageSize for StillSize {
+    const KIND: ImageKind = ImageKind::Still;
+}
+
+impl fmt::Display for StillSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The `images` object of the API configuration response.
+#[derive(Deserialize)]
+struct ImagesConfiguration {
+    secure_base_url: String,
+    backdrop_sizes: Vec<String>,
+    logo_sizes: Vec<String>,
+    poster_sizes: Vec<String>,
+    profile_sizes: Vec<String>,
+    still_sizes: Vec<String>,
+}
+
+/// The API configuration response.
+#[derive(Deserialize)]
+struct ApiConfiguration {
+    images: ImagesConfiguration,
+}
+
+/// A builder for image URLs.
+///
+/// TMDB responses only contain the file paths of images, e.g.,
+/// `poster_path`. An [`ImageUrlBuilder`] combines them with the base URL and
+/// sizes from the API configuration to build full image URLs.
+///
+/// An [`ImageUrlBuilder`] deserializes from the response of
+/// [`configuration::ApiConfiguration`], so it can be sent like any other
+/// response type.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::error::Error;
+///
+/// use eiga::{ImageUrlBuilder, PosterSize, Tmdb};
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let tmdb = Tmdb::new("<token>");
+///     let image_url_builder = ImageUrlBuilder::fetch(&tmdb)?;
+///
+///     let url = image_url_builder
+///         .poster("/kqjL17yufvn9OVLyXYpvtyrFfak.jpg", PosterSize::W500)?;
+///
+///     assert_eq!(
+///         "https://image.tmdb.org/t/p/w500/kqjL17yufvn9OVLyXYpvtyrFfak.jpg",
+///         url.as_str()
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`configuration::ApiConfiguration`]: configuration/struct.ApiConfiguration.html
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "ApiConfiguration")]
+pub struct ImageUrlBuilder {
+    base_url: Url,
+    backdrop_sizes: Vec<String>,
+    logo_sizes: Vec<String>,
+    poster_sizes: Vec<String>,
+    profile_sizes: Vec<String>,
+    still_sizes: Vec<String>,
+}
+
+impl ImageUrlBuilder {
+    /// Fetches the API configuration and constructs a new [`ImageUrlBuilder`]
+    /// from it.
+    pub fn fetch<C>(client: &C) -> Result<ImageUrlBuilder, Error>
+    where
+        C: Client + ?Sized,
+    {
+        client.send(&configuration::ApiConfiguration::new())
+    }
+
+    /// Returns the base URL of images.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Returns the URL of a poster image.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a poster
+    /// size.
+    pub fn poster(&self, path: &str, size: PosterSize) -> Result<Url, Error> {
+        self.url(path, size)
+    }
+
+    /// Returns the URL of a backdrop image.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a backdrop
+    /// size.
+    pub fn backdrop(
+        &self,
+        path: &str,
+        size: BackdropSize,
+    ) -> Result<Url, Error> {
+        self.url(path, size)
+    }
+
+    /// Returns the URL of a logo image.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a logo
+    /// size.
+    pub fn logo(&self, path: &str, size: LogoSize) -> Result<Url, Error> {
+        self.url(path, size)
+    }
+
+    /// Returns the URL of a profile image.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a profile
+    /// size.
+    pub fn profile(
+        &self,
+        path: &str,
+        size: ProfileSize,
+    ) -> Result<Url, Error> {
+        self.url(path, size)
+    }
+
+    /// Returns the URL of a still image.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a still
+    /// size.
+    pub fn still(&self, path: &str, size: StillSize) -> Result<Url, Error> {
+        self.url(path, size)
+    }
+
+    /// Returns the URL of the image with the given path and size.
+    ///
+    /// This fails if the API configuration doesn't list `size` as a size of
+    /// its kind of image.
+    pub fn url<S>(&self, path: &str, size: S) -> Result<Url, Error>
+    where
+        S: ImageSize,
+    {
+        let size = size.to_string();
+        if !self.sizes(S::KIND).contains(&size) {
+            return Err(Error::Validation(format!(
+                "{} isn't an available {} size",
+                size,
+                S::KIND
+            )));
+        }
+
+        // Image paths start with a slash, which would replace the path of the
+        // base URL when joined.
+        let url = self.base_url.join(&format!(
+            "{}/{}",
+            size,
+            path.trim_start_matches('/')
+        ))?;
+
+        Ok(url)
+    }
+
+    /// Returns the sizes of the given kind of image that the API
+    /// configuration lists.
+    pub fn sizes(&self, kind: ImageKind) -> &[String] {
+        match kind {
+            ImageKind::Backdrop => &self.backdrop_sizes,
+            ImageKind::Logo => &self.logo_sizes,
+            ImageKind::Poster => &self.poster_sizes,
+            ImageKind::Profile => &self.profile_sizes,
+            ImageKind::Still => &self.still_sizes,
+        }
+    }
+}
+
+impl TryFrom<ApiConfiguration> for ImageUrlBuilder {
+    type Error = url::ParseError;
+
+    fn try_from(
+        api_configuration: ApiConfiguration,
+    ) -> Result<ImageUrlBuilder, url::ParseError> {
+        let images = api_configuration.images;
+
+        Ok(ImageUrlBuilder {
+            base_url: Url::parse(&images.secure_base_url)?,
+            backdrop_sizes: images.backdrop_sizes,
+            logo_sizes: images.logo_sizes,
+            poster_sizes: images.poster_sizes,
+            profile_sizes: images.profile_sizes,
+            still_sizes: images.still_sizes,
+        })
+    }
+}
+
+/// An on-disk, content-addressed store of downloaded images.
+///
+/// Images are stored by the SHA-256 hash of their content, at
+/// `<dir>/<hash>.<extension>`, so identical images are only stored once. An
+/// index at `<dir>/index/<size>/<file name>` records the hash of each
+/// downloaded image, so an image is only downloaded once.
+///
+/// Downloads go through [`Tmdb::download_image`], so they're retried and
+/// rate limited like the `Tmdb`'s other requests.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::error::Error;
+///
+/// use eiga::{ImageStore, ImageUrlBuilder, PosterSize, Tmdb};
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let tmdb = Tmdb::new("<token>");
+///     let image_url_builder = ImageUrlBuilder::fetch(&tmdb)?;
+///     let image_store = ImageStore::new("images");
+///
+///     // The second call finds the stored image and skips the download.
+///     for _ in 0..2 {
+///         image_store.download(
+///             &tmdb,
+///             &image_url_builder,
+///             "/kqjL17yufvn9OVLyXYpvtyrFfak.jpg",
+///             PosterSize::W500,
+///         )?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ImageStore {
+    dir: PathBuf,
+}
+
+impl ImageStore {
+    /// Constructs a new [`ImageStore`] that stores images in `dir`.
+    ///
+    /// The directory is created when the first image is stored.
+    pub fn new<P>(dir: P) -> ImageStore
+    where
+        P: Into<PathBuf>,
+    {
+        ImageStore { dir: dir.into() }
+    }
+
+    /// Returns the path of the stored image with the given path and size,
+    /// or `None` if it isn't stored.
+    ///
+    /// This fails if `path` doesn't end in a file name.
+    pub fn path<S>(
+        &self,
+        path: &str,
+        size: S,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        S: ImageSize,
+    {
+        let name = match fs::read_to_string(self.index_path(path, size)?) {
+            Ok(name) => name,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(None)
+            }
+            Err(err) => return Err(Error::Io(err)),
+        };
+
+        // Only use the file name so that an edited index can't point outside
+        // of the store.
+        let stored_path = match Path::new(name.trim()).file_name() {
+            Some(name) => self.dir.join(name),
+            None => return Ok(None),
+        };
+
+        Ok(Some(stored_path).filter(|stored_path| stored_path.exists()))
+    }
+
+    /// Downloads the image with the given path and size unless it's already
+    /// stored. Returns the path of the stored image.
+    pub fn download<S>(
+        &self,
+        tmdb: &Tmdb,
+        image_url_builder: &ImageUrlBuilder,
+        path: &str,
+        size: S,
+    ) -> Result<PathBuf, Error>
+    where
+        S: ImageSize,
+    {
+        if let Some(stored_path) = self.path(path, size)? {
+            return Ok(stored_path);
+        }
+
+        let index_path = self.index_path(path, size)?;
+        // `index_path` always returns a path inside of the index directory,
+        // so it's safe to unwrap here.
+        fs::create_dir_all(index_path.parent().unwrap()).map_err(Error::Io)?;
+
+        // Download to a temporary file first so that a failed download
+        // doesn't leave a partial image behind, and because the hash isn't
+        // known until the whole image is received.
+        let temporary_path = self.dir.join(format!(
+            "{}.{}.part",
+            process::id(),
+            NEXT_DOWNLOAD.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let result = File::create(&temporary_path)
+            .map_err(Error::Io)
+            .and_then(|file| {
+                let mut writer = HashWriter::new(file);
+                tmdb.download_image(
+                    image_url_builder,
+                    path,
+                    size,
+                    &mut writer,
+                )?;
+
+                Ok(writer.finish())
+            })
+            .and_then(|hash| {
+                let name = match Path::new(path).extension() {
+                    Some(extension) => {
+                        format!("{}.{}", hash, extension.to_string_lossy())
+                    }
+                    None => hash,
+                };
+                let stored_path = self.dir.join(&name);
+
+                // If an identical image is already stored, this replaces it
+                // with the same content.
+                fs::rename(&temporary_path, &stored_path)
+                    .and_then(|_| fs::write(&index_path, name))
+                    .map_err(Error::Io)?;
+
+                Ok(stored_path)
+            });
+        if result.is_err() {
+            let _ = fs::remove_file(&temporary_path);
+        }
+
+        result
+    }
+
+    /// Returns the path of the index entry of the image with the given path
+    /// and size.
+    ///
+    /// This fails if `path` doesn't end in a file name.
+    fn index_path<S>(&self, path: &str, size: S) -> Result<PathBuf, Error>
+    where
+        S: ImageSize,
+    {
+        // Only use the file name so that a path like `../x.jpg` can't escape
+        // the store.
+        let file_name = Path::new(path).file_name().ok_or_else(|| {
+            Error::Validation(format!("{} isn't an image path", path))
+        })?;
+
+        Ok(self
+            .dir
+            .join("index")
+            .join(size.to_string())
+            .join(file_name))
+    }
+}
+
+/// A counter that makes the temporary files of concurrent downloads unique.
+static NEXT_DOWNLOAD: AtomicUsize = AtomicUsize::new(0);
+
+/// A writer that hashes everything written to it with SHA-256.
+struct HashWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W> HashWriter<W> {
+    /// Constructs a new `HashWriter` that writes to `inner`.
+    fn new(inner: W) -> HashWriter<W> {
+        HashWriter {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// Returns the hash of everything written, in lowercase hex.
+    fn finish(self) -> String {
+        self.hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+impl<W> io::Write for HashWriter<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
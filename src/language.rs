@@ -1,4 +1,7 @@
 use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
 
 /// The languages used in TMDB.
 #[derive(Debug)]
@@ -572,3 +575,201 @@ impl fmt::Display for Language {
         }
     }
 }
+
+impl FromStr for Language {
+    type Err = Error;
+
+    /// Parses a language from its code. The code is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "an" => Ok(Language::An),
+            "ak" => Ok(Language::Ak),
+            "cr" => Ok(Language::Cr),
+            "az" => Ok(Language::Az),
+            "cs" => Ok(Language::Cs),
+            "aa" => Ok(Language::Aa),
+            "br" => Ok(Language::Br),
+            "af" => Ok(Language::Af),
+            "bo" => Ok(Language::Bo),
+            "ce" => Ok(Language::Ce),
+            "kw" => Ok(Language::Kw),
+            "fo" => Ok(Language::Fo),
+            "la" => Ok(Language::La),
+            "ng" => Ok(Language::Ng),
+            "sc" => Ok(Language::Sc),
+            "ti" => Ok(Language::Ti),
+            "tn" => Ok(Language::Tn),
+            "tr" => Ok(Language::Tr),
+            "pa" => Ok(Language::Pa),
+            "et" => Ok(Language::Et),
+            "fr" => Ok(Language::Fr),
+            "ha" => Ok(Language::Ha),
+            "is" => Ok(Language::Is),
+            "li" => Ok(Language::Li),
+            "ln" => Ok(Language::Ln),
+            "ss" => Ok(Language::Ss),
+            "ab" => Ok(Language::Ab),
+            "sh" => Ok(Language::Sh),
+            "eu" => Ok(Language::Eu),
+            "fy" => Ok(Language::Fy),
+            "ja" => Ok(Language::Ja),
+            "oj" => Ok(Language::Oj),
+            "or" => Ok(Language::Or),
+            "pi" => Ok(Language::Pi),
+            "su" => Ok(Language::Su),
+            "th" => Ok(Language::Th),
+            "ig" => Ok(Language::Ig),
+            "id" => Ok(Language::Id),
+            "kk" => Ok(Language::Kk),
+            "ki" => Ok(Language::Ki),
+            "ug" => Ok(Language::Ug),
+            "ve" => Ok(Language::Ve),
+            "rw" => Ok(Language::Rw),
+            "mi" => Ok(Language::Mi),
+            "nv" => Ok(Language::Nv),
+            "hi" => Ok(Language::Hi),
+            "pt" => Ok(Language::Pt),
+            "sg" => Ok(Language::Sg),
+            "sk" => Ok(Language::Sk),
+            "sr" => Ok(Language::Sr),
+            "ty" => Ok(Language::Ty),
+            "xh" => Ok(Language::Xh),
+            "ar" => Ok(Language::Ar),
+            "co" => Ok(Language::Co),
+            "bi" => Ok(Language::Bi),
+            "eo" => Ok(Language::Eo),
+            "hz" => Ok(Language::Hz),
+            "fi" => Ok(Language::Fi),
+            "iu" => Ok(Language::Iu),
+            "lv" => Ok(Language::Lv),
+            "it" => Ok(Language::It),
+            "nl" => Ok(Language::Nl),
+            "kn" => Ok(Language::Kn),
+            "sa" => Ok(Language::Sa),
+            "sq" => Ok(Language::Sq),
+            "tl" => Ok(Language::Tl),
+            "lb" => Ok(Language::Lb),
+            "ts" => Ok(Language::Ts),
+            "ml" => Ok(Language::Ml),
+            "vo" => Ok(Language::Vo),
+            "zu" => Ok(Language::Zu),
+            "os" => Ok(Language::Os),
+            "sm" => Ok(Language::Sm),
+            "za" => Ok(Language::Za),
+            "bn" => Ok(Language::Bn),
+            "cu" => Ok(Language::Cu),
+            "ga" => Ok(Language::Ga),
+            "gv" => Ok(Language::Gv),
+            "hu" => Ok(Language::Hu),
+            "jv" => Ok(Language::Jv),
+            "kr" => Ok(Language::Kr),
+            "km" => Ok(Language::Km),
+            "ky" => Ok(Language::Ky),
+            "na" => Ok(Language::Na),
+            "nr" => Ok(Language::Nr),
+            "oc" => Ok(Language::Oc),
+            "ro" => Ok(Language::Ro),
+            "ru" => Ok(Language::Ru),
+            "hy" => Ok(Language::Hy),
+            "ch" => Ok(Language::Ch),
+            "xx" => Ok(Language::Xx),
+            "ba" => Ok(Language::Ba),
+            "gl" => Ok(Language::Gl),
+            "io" => Ok(Language::Io),
+            "lu" => Ok(Language::Lu),
+            "mh" => Ok(Language::Mh),
+            "mg" => Ok(Language::Mg),
+            "mo" => Ok(Language::Mo),
+            "mn" => Ok(Language::Mn),
+            "nd" => Ok(Language::Nd),
+            "no" => Ok(Language::No),
+            "pl" => Ok(Language::Pl),
+            "sw" => Ok(Language::Sw),
+            "tg" => Ok(Language::Tg),
+            "to" => Ok(Language::To),
+            "wa" => Ok(Language::Wa),
+            "yi" => Ok(Language::Yi),
+            "en" => Ok(Language::En),
+            "as" => Ok(Language::As),
+            "gd" => Ok(Language::Gd),
+            "kl" => Ok(Language::Kl),
+            "my" => Ok(Language::My),
+            "qu" => Ok(Language::Qu),
+            "sn" => Ok(Language::Sn),
+            "uk" => Ok(Language::Uk),
+            "fa" => Ok(Language::Fa),
+            "ka" => Ok(Language::Ka),
+            "gu" => Ok(Language::Gu),
+            "av" => Ok(Language::Av),
+            "ae" => Ok(Language::Ae),
+            "gn" => Ok(Language::Gn),
+            "mt" => Ok(Language::Mt),
+            "ne" => Ok(Language::Ne),
+            "sv" => Ok(Language::Sv),
+            "tt" => Ok(Language::Tt),
+            "wo" => Ok(Language::Wo),
+            "cn" => Ok(Language::Cn),
+            "cv" => Ok(Language::Cv),
+            "da" => Ok(Language::Da),
+            "dz" => Ok(Language::Dz),
+            "ny" => Ok(Language::Ny),
+            "rn" => Ok(Language::Rn),
+            "st" => Ok(Language::St),
+            "tk" => Ok(Language::Tk),
+            "uz" => Ok(Language::Uz),
+            "vi" => Ok(Language::Vi),
+            "el" => Ok(Language::El),
+            "ca" => Ok(Language::Ca),
+            "cy" => Ok(Language::Cy),
+            "de" => Ok(Language::De),
+            "ks" => Ok(Language::Ks),
+            "ms" => Ok(Language::Ms),
+            "nb" => Ok(Language::Nb),
+            "rm" => Ok(Language::Rm),
+            "si" => Ok(Language::Si),
+            "es" => Ok(Language::Es),
+            "te" => Ok(Language::Te),
+            "tw" => Ok(Language::Tw),
+            "ps" => Ok(Language::Ps),
+            "bg" => Ok(Language::Bg),
+            "mk" => Ok(Language::Mk),
+            "ik" => Ok(Language::Ik),
+            "ko" => Ok(Language::Ko),
+            "lt" => Ok(Language::Lt),
+            "om" => Ok(Language::Om),
+            "se" => Ok(Language::Se),
+            "so" => Ok(Language::So),
+            "ta" => Ok(Language::Ta),
+            "ur" => Ok(Language::Ur),
+            "am" => Ok(Language::Am),
+            "bs" => Ok(Language::Bs),
+            "dv" => Ok(Language::Dv),
+            "ay" => Ok(Language::Ay),
+            "bm" => Ok(Language::Bm),
+            "ii" => Ok(Language::Ii),
+            "ie" => Ok(Language::Ie),
+            "kv" => Ok(Language::Kv),
+            "ku" => Ok(Language::Ku),
+            "nn" => Ok(Language::Nn),
+            "zh" => Ok(Language::Zh),
+            "he" => Ok(Language::He),
+            "ee" => Ok(Language::Ee),
+            "fj" => Ok(Language::Fj),
+            "ff" => Ok(Language::Ff),
+            "ht" => Ok(Language::Ht),
+            "hr" => Ok(Language::Hr),
+            "ia" => Ok(Language::Ia),
+            "kj" => Ok(Language::Kj),
+            "lo" => Ok(Language::Lo),
+            "lg" => Ok(Language::Lg),
+            "mr" => Ok(Language::Mr),
+            "sd" => Ok(Language::Sd),
+            "be" => Ok(Language::Be),
+            "ho" => Ok(Language::Ho),
+            "kg" => Ok(Language::Kg),
+            "sl" => Ok(Language::Sl),
+            "yo" => Ok(Language::Yo),
+            _ => Err(Error::Validation(format!("unknown language: {}", s))),
+        }
+    }
+}
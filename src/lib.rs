@@ -53,23 +53,51 @@
 //! ```
 
 #![deny(missing_debug_implementations, missing_docs)]
+// `Error` is large because of `ureq::Transport`, but boxing it isn't worth
+// the churn in the public API.
+#![allow(clippy::result_large_err)]
 
 mod api;
+mod backend;
+mod batch;
+mod body;
 mod client;
+mod config;
 mod country;
 mod endpoint;
 mod error;
+mod image;
 mod language;
 mod page;
 mod parameters;
+mod prepared_request;
+mod throttle;
 mod tmdb;
+#[cfg(feature = "ureq")]
+mod ureq_backend;
+mod validation;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use api::*;
+pub use backend::{HttpBackend, HttpResponse, HttpStream};
+pub use batch::{Completed, SendMany};
+pub use body::Body;
 pub use client::Client;
 pub use country::Country;
-pub use endpoint::Endpoint;
+pub use endpoint::{ApiVersion, Endpoint};
 pub use error::Error;
+pub use image::{
+    BackdropSize, ImageKind, ImageSize, ImageStore, ImageUrlBuilder, LogoSize,
+    PosterSize, ProfileSize, StillSize,
+};
 pub use language::Language;
-pub use page::{Page, PageIter, Pageable};
+pub use page::{
+    Cursor, Envelope, OwnedPageIter, Page, PageIter, Pageable, Pages,
+};
 pub use parameters::{Parameters, Value};
-pub use tmdb::Tmdb;
+pub use prepared_request::PreparedRequest;
+pub use tmdb::{Tmdb, TmdbBuilder};
+#[cfg(feature = "ureq")]
+pub use ureq_backend::UreqBackend;
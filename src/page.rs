@@ -1,8 +1,14 @@
 use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Deref;
 
-use serde::{de::DeserializeOwned, Deserialize};
+use http::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{Client, Endpoint, Error, Parameters};
+use crate::endpoint::DEFAULT_PARAMETERS;
+use crate::validation::{self, MAX_PAGE};
+use crate::{ApiVersion, Client, Endpoint, Error, Parameters};
 
 /// The response type of pageable endpoints.
 #[derive(Debug, Deserialize)]
@@ -10,9 +16,15 @@ pub struct Page<T> {
     /// The page number.
     pub page: u16,
     /// The page results.
+    ///
+    /// Some endpoints, like v3 list details, call their results something
+    /// else. Their [`Envelope`]s map those fields to this one.
     pub results: Vec<T>,
-    /// The number of results in the page.
-    pub total_results: usize,
+    /// The number of results for the request.
+    ///
+    /// This is `None` if the endpoint doesn't respond with it.
+    #[serde(default)]
+    pub total_results: Option<usize>,
     /// The number of pages for the request.
     pub total_pages: u16,
 }
@@ -27,69 +39,658 @@ where
     }
 }
 
+/// A description of where a pageable endpoint's response keeps its page.
+///
+/// Most pageable endpoints respond with the shape of [`Page`]. Endpoints
+/// that name their fields differently describe their shape by overriding
+/// [`Pageable::envelope`].
+///
+/// A response without `page` is treated as page 1, and a response without
+/// `total_pages` is treated as the last page. Other fields, like the `id` of
+/// movie reviews, are ignored.
+///
+/// # Example
+///
+/// ```
+/// use eiga::Envelope;
+/// use serde_json::json;
+///
+/// let envelope = Envelope::new().results("items").total_results("item_count");
+/// let page = envelope
+///     .page::<u64>(json!({ "items": [1, 2], "item_count": 2 }))
+///     .unwrap();
+///
+/// assert_eq!(1, page.page);
+/// assert_eq!(vec![1, 2], page.results);
+/// assert_eq!(Some(2), page.total_results);
+/// assert!(page.is_last_page());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Envelope {
+    results: String,
+    total_results: String,
+}
+
+impl Envelope {
+    /// Constructs a new `Envelope` for the shape of [`Page`].
+    pub fn new() -> Envelope {
+        Envelope {
+            results: "results".to_owned(),
+            total_results: "total_results".to_owned(),
+        }
+    }
+
+    /// Sets the field that holds the page results.
+    pub fn results<S>(mut self, field: S) -> Envelope
+    where
+        S: Into<String>,
+    {
+        self.results = field.into();
+
+        self
+    }
+
+    /// Sets the field that holds the number of results for the request.
+    pub fn total_results<S>(mut self, field: S) -> Envelope
+    where
+        S: Into<String>,
+    {
+        self.total_results = field.into();
+
+        self
+    }
+
+    /// Extracts the page from the given response.
+    pub fn page<D>(&self, mut response: Value) -> Result<Page<D>, Error>
+    where
+        D: DeserializeOwned,
+    {
+        let results = take_field(&mut response, &self.results)?;
+        let total_results = take_field(&mut response, &self.total_results)?;
+        let page: Option<u16> = take_field(&mut response, "page")?;
+        let page = page.unwrap_or(1);
+        let total_pages: Option<u16> =
+            take_field(&mut response, "total_pages")?;
+
+        Ok(Page {
+            page,
+            results,
+            total_results,
+            total_pages: total_pages.unwrap_or(page),
+        })
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Envelope {
+        Envelope::new()
+    }
+}
+
+/// Takes `field` out of `response` and deserializes it. A missing field is
+/// deserialized from `null`.
+fn take_field<T>(response: &mut Value, field: &str) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let value = response.get_mut(field).map(Value::take);
+
+    serde_json::from_value(value.unwrap_or(Value::Null))
+        .map_err(|err| Error::Deserialize(err.into()))
+}
+
 /// A trait for pageable endpoint objects.
 pub trait Pageable: Endpoint {
     /// Returns the starting page of the endpoint.
     fn start_page(&self) -> Option<u16>;
+
+    /// Returns the shape of the endpoint's responses.
+    ///
+    /// The default is the shape of [`Page`].
+    fn envelope(&self) -> Envelope {
+        Envelope::new()
+    }
+}
+
+/// Fetches the given page of a pageable endpoint.
+fn fetch_page<C, E, D>(
+    client: &C,
+    endpoint: &E,
+    page: u16,
+) -> Result<Page<D>, Error>
+where
+    C: Client + ?Sized,
+    E: Pageable + ?Sized,
+    D: DeserializeOwned,
+{
+    let response = client.send(&PageEndpoint { endpoint, page })?;
+
+    endpoint.envelope().page(response)
 }
 
+/// A pageable endpoint with its page set to a specific page.
 #[derive(Debug)]
-struct PageIterState<'a, C, E>
+struct PageEndpoint<'a, E>
 where
-    C: ?Sized,
+    E: ?Sized,
 {
-    client: &'a C,
     endpoint: &'a E,
+    page: u16,
+}
+
+impl<'a, E> Endpoint for PageEndpoint<'a, E>
+where
+    E: Pageable + ?Sized,
+{
+    fn method(&self) -> http::Method {
+        self.endpoint.method()
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        self.endpoint.api_version()
+    }
+
+    fn path(&self) -> std::borrow::Cow<'static, str> {
+        self.endpoint.path()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = self.endpoint.parameters();
+        parameters.replace("page", self.page);
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.endpoint.validate()
+    }
+
+    fn is_success(&self, status: u16, status_code: Option<u32>) -> bool {
+        self.endpoint.is_success(status, status_code)
+    }
+
+    fn requires_session(&self) -> bool {
+        self.endpoint.requires_session()
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        self.endpoint.accepts_default(name)
+    }
+}
+
+/// Fetches the given pages with at most the given number of requests in
+/// flight at once.
+type FetchPages<C, E, D> =
+    fn(&C, &E, std::ops::Range<u16>, usize) -> Vec<Result<Page<D>, Error>>;
+
+/// Fetches the given pages in parallel, on a bounded set of worker threads.
+///
+/// The pages are sent as a batch with [`Client::send_many`], so each worker
+/// fetches pages until there are none left.
+fn fetch_pages_concurrent<C, E, D>(
+    client: &C,
+    endpoint: &E,
+    pages: std::ops::Range<u16>,
+    concurrency: usize,
+) -> Vec<Result<Page<D>, Error>>
+where
+    C: Client + Sync,
+    E: Pageable + Sync,
+    D: DeserializeOwned + Send,
+{
+    let envelope = endpoint.envelope();
+
+    client
+        .send_many(pages.map(|page| PageEndpoint { endpoint, page }))
+        .concurrency(concurrency)
+        .in_order::<Value>()
+        .into_iter()
+        .map(|response| envelope.page(response?))
+        .collect()
+}
+
+/// A client or endpoint that an iterator either borrows or owns.
+enum Handle<'a, T>
+where
+    T: ?Sized,
+{
+    Borrowed(&'a T),
+    Owned(Box<T>),
+}
+
+impl<'a, T> Deref for Handle<'a, T>
+where
+    T: ?Sized,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Handle::Borrowed(value) => value,
+            Handle::Owned(value) => value,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for Handle<'a, T>
+where
+    T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// A saved position in the results of a pageable endpoint.
+///
+/// A cursor holds everything needed to send the endpoint's request again,
+/// so it can be serialized, stored, and later used to resume iteration with
+/// [`PageIter::resume`], even from another process. Get one from
+/// [`PageIter::cursor`] or [`Pages::cursor`].
+///
+/// A cursor is a pageable endpoint itself. Its page is the page of the next
+/// result and its offset is the number of results of that page that were
+/// already yielded.
+///
+/// # Example
+///
+/// ```no_run
+/// use eiga::{search, Client, Cursor, PageIter, Tmdb};
+///
+/// let tmdb = Tmdb::new("<token>");
+/// let endpoint = search::Movies::new("Godzilla");
+///
+/// let mut page_iter = tmdb.page::<_, serde_json::Value>(&endpoint);
+/// page_iter.next();
+/// let saved = serde_json::to_string(&page_iter.cursor().unwrap()).unwrap();
+///
+/// // Later, maybe after a restart:
+/// let cursor: Cursor = serde_json::from_str(&saved).unwrap();
+/// let page_iter = PageIter::<_, _, serde_json::Value>::resume(&tmdb, &cursor);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    method: String,
+    api_version: ApiVersion,
+    path: String,
+    parameters: Vec<(String, String)>,
+    requires_session: bool,
+    #[serde(default)]
+    accepts_defaults: Vec<String>,
+    #[serde(default)]
+    envelope: Envelope,
+    page: u16,
+    offset: usize,
+}
+
+impl Cursor {
+    /// Constructs a new `Cursor` at the given position in the results of
+    /// `endpoint`.
+    fn new<E>(endpoint: &E, page: u16, offset: usize) -> Cursor
+    where
+        E: Pageable + ?Sized,
+    {
+        let parameters = endpoint
+            .parameters()
+            .iter()
+            .filter(|(key, _)| *key != "page")
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+
+        Cursor {
+            method: endpoint.method().as_str().to_owned(),
+            api_version: endpoint.api_version(),
+            path: endpoint.path().into_owned(),
+            parameters,
+            requires_session: endpoint.requires_session(),
+            accepts_defaults: DEFAULT_PARAMETERS
+                .iter()
+                .filter(|name| endpoint.accepts_default(name))
+                .map(|name| name.to_string())
+                .collect(),
+            envelope: endpoint.envelope(),
+            page,
+            offset,
+        }
+    }
+
+    /// Returns the page of the next result.
+    pub fn page(&self) -> u16 {
+        self.page
+    }
+
+    /// Returns the number of results of the page that were already yielded.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Endpoint for Cursor {
+    fn method(&self) -> Method {
+        // `validate` rejects invalid methods before a request is sent.
+        Method::from_bytes(self.method.as_bytes()).unwrap_or(Method::GET)
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    fn path(&self) -> std::borrow::Cow<'static, str> {
+        self.path.clone().into()
+    }
+
+    fn parameters(&self) -> Parameters<'_> {
+        let mut parameters = Parameters::new();
+        for (key, value) in &self.parameters {
+            parameters.push(key, Some(value.as_str()));
+        }
+        parameters.push("page", Some(self.page));
+
+        parameters
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if Method::from_bytes(self.method.as_bytes()).is_err() {
+            return Err(Error::Validation(format!(
+                "cursor has an invalid method: {}",
+                self.method
+            )));
+        }
+
+        validation::page(Some(self.page))
+    }
+
+    fn requires_session(&self) -> bool {
+        self.requires_session
+    }
+
+    fn accepts_default(&self, name: &str) -> bool {
+        self.accepts_defaults
+            .iter()
+            .any(|accepted| accepted == name)
+    }
+}
+
+impl Pageable for Cursor {
+    fn start_page(&self) -> Option<u16> {
+        Some(self.page)
+    }
+
+    fn envelope(&self) -> Envelope {
+        self.envelope.clone()
+    }
+}
+
+/// A pageable pages iterator.
+///
+/// By default, pages are fetched one at a time as they're consumed. Use
+/// [`Pages::page_concurrent`] to fetch pages in parallel.
+pub struct Pages<'a, C, E, T>
+where
+    C: ?Sized,
+{
+    client: Handle<'a, C>,
+    endpoint: Handle<'a, E>,
     next_page: Option<u16>,
+    total_pages: Option<u16>,
+    total_results: Option<usize>,
+    page_size: Option<usize>,
+    max_pages: Option<u16>,
+    pages_fetched: u16,
+    concurrency: usize,
+    fetch_pages: Option<FetchPages<C, E, T>>,
+    pages: VecDeque<Page<T>>,
+    error: Option<Error>,
 }
 
-impl<'a, C, E> PageIterState<'a, C, E>
+impl<'a, C, E, D> Pages<'a, C, E, D>
 where
     C: Client,
     E: Pageable,
+    D: DeserializeOwned,
 {
-    fn new(client: &'a C, endpoint: &'a E) -> PageIterState<'a, C, E> {
-        PageIterState {
+    fn new(
+        client: Handle<'a, C>,
+        endpoint: Handle<'a, E>,
+    ) -> Pages<'a, C, E, D> {
+        Pages {
+            next_page: endpoint.start_page().or(Some(1)),
             client,
             endpoint,
-            next_page: endpoint.start_page().or(Some(1)),
+            total_pages: None,
+            total_results: None,
+            page_size: None,
+            max_pages: None,
+            pages_fetched: 0,
+            concurrency: 1,
+            fetch_pages: None,
+            pages: VecDeque::new(),
+            error: None,
         }
     }
+
+    /// Fetches up to `concurrency` pages in parallel.
+    ///
+    /// The first page is always fetched on its own to learn the number of
+    /// pages. After that, the following pages are fetched in batches of
+    /// `concurrency` pages, on at most `concurrency` worker threads. Pages
+    /// are still yielded in order.
+    ///
+    /// A `concurrency` of 0 or 1 fetches pages one at a time.
+    pub fn page_concurrent(mut self, concurrency: usize) -> Self
+    where
+        C: Sync,
+        E: Sync,
+        D: Send,
+    {
+        self.concurrency = concurrency.max(1);
+        self.fetch_pages = Some(fetch_pages_concurrent::<C, E, D>);
+
+        self
+    }
+
+    /// Stops after fetching `max_pages` pages.
+    pub fn max_pages(mut self, max_pages: u16) -> Self {
+        self.max_pages = Some(max_pages);
+
+        self
+    }
+
+    /// Returns the number of results for the request.
+    ///
+    /// This is `None` until the first page is fetched, or if the endpoint
+    /// doesn't respond with it.
+    pub fn total_results(&self) -> Option<usize> {
+        self.total_results
+    }
+
+    /// Returns the number of pages for the request.
+    ///
+    /// This is `None` until the first page is fetched.
+    pub fn total_pages(&self) -> Option<u16> {
+        self.total_pages
+    }
+
+    /// Returns `true` if there are more pages than TMDB responds with.
+    ///
+    /// TMDB only responds with the first 500 pages of a request, so the
+    /// iterator stops there and the rest of the results are unreachable.
+    /// This is `false` until the first page is fetched.
+    pub fn is_truncated(&self) -> bool {
+        self.total_pages
+            .is_some_and(|total_pages| total_pages > MAX_PAGE)
+    }
+
+    /// Returns a cursor at the next page, or `None` if there are no pages
+    /// left.
+    pub fn cursor(&self) -> Option<Cursor> {
+        let page = match self.pages.front() {
+            Some(page) => page.page,
+            None => self.next_page?,
+        };
+
+        Some(Cursor::new(&*self.endpoint, page, 0))
+    }
+
+    /// Returns the next page, fetching at most `max_batch` pages if there
+    /// are no buffered pages.
+    fn next_page(&mut self, max_batch: u16) -> Option<Result<Page<D>, Error>> {
+        if let Some(page) = self.pages.pop_front() {
+            return Some(Ok(page));
+        }
+        if let Some(err) = self.error.take() {
+            return Some(Err(err));
+        }
+
+        let page = self.next_page?;
+        let pages_left = match self.max_pages {
+            Some(max_pages) => max_pages.saturating_sub(self.pages_fetched),
+            None => u16::MAX,
+        };
+        if pages_left == 0 {
+            return None;
+        }
+
+        if let Err(err) = self.fetch(page, max_batch.min(pages_left)) {
+            if self.pages.is_empty() {
+                return Some(Err(err));
+            }
+
+            // Yield the pages before the failed page first.
+            self.error = Some(err);
+        }
+
+        self.pages.pop_front().map(Ok)
+    }
+
+    /// Fetches up to `max_batch` pages, starting at `page`, and buffers
+    /// them.
+    ///
+    /// If a page fails, the pages before it are still buffered and the
+    /// failed page is fetched again on the next call.
+    fn fetch(&mut self, page: u16, max_batch: u16) -> Result<(), Error> {
+        let batch_size = u16::try_from(self.concurrency)
+            .unwrap_or(u16::MAX)
+            .min(max_batch)
+            .max(1);
+
+        let pages = match (self.fetch_pages, self.total_pages) {
+            (Some(fetch_pages), Some(total_pages)) if batch_size > 1 => {
+                let last_page = total_pages.min(MAX_PAGE);
+                let end = last_page.min(page.saturating_add(batch_size - 1));
+                fetch_pages(
+                    &self.client,
+                    &self.endpoint,
+                    page..end + 1,
+                    self.concurrency,
+                )
+            }
+            _ => vec![fetch_page(&*self.client, &*self.endpoint, page)],
+        };
+
+        for response in pages {
+            let response = response?;
+
+            self.total_pages = Some(response.total_pages);
+            self.total_results = response.total_results;
+            self.page_size.get_or_insert(response.results.len());
+            self.pages_fetched += 1;
+            // TMDB doesn't respond with pages past `MAX_PAGE`, so stop there
+            // even if there are more pages.
+            let last_page = response.total_pages.min(MAX_PAGE);
+            self.next_page = if response.page < last_page {
+                Some(response.page + 1)
+            } else {
+                None
+            };
+
+            self.pages.push_back(response);
+        }
+
+        Ok(())
+    }
 }
 
-impl<'a, C, E> Endpoint for PageIterState<'a, C, E>
+impl<'a, C, E, D> Iterator for Pages<'a, C, E, D>
 where
     C: Client,
     E: Pageable,
+    D: DeserializeOwned,
 {
-    fn method(&self) -> http::Method {
-        self.endpoint.method()
-    }
+    type Item = Result<Page<D>, Error>;
 
-    fn path(&self) -> std::borrow::Cow<'static, str> {
-        self.endpoint.path()
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_page(u16::MAX)
     }
+}
 
-    fn parameters(&self) -> Parameters {
-        let mut parameters = self.endpoint.parameters();
-        if let Some(next_page) = self.next_page {
-            parameters.replace("page", next_page);
-        }
-
-        parameters
+impl<'a, C, E, T> fmt::Debug for Pages<'a, C, E, T>
+where
+    C: fmt::Debug + ?Sized,
+    E: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pages")
+            .field("client", &self.client)
+            .field("endpoint", &self.endpoint)
+            .field("next_page", &self.next_page)
+            .field("total_pages", &self.total_pages)
+            .field("total_results", &self.total_results)
+            .field("max_pages", &self.max_pages)
+            .field("pages_fetched", &self.pages_fetched)
+            .field("concurrency", &self.concurrency)
+            .field("pages", &self.pages)
+            .field("error", &self.error)
+            .finish()
     }
 }
 
 /// A pageable results iterator.
+///
+/// By default, pages are fetched one at a time as the results are consumed.
+/// Use [`PageIter::page_concurrent`] to fetch pages in parallel.
+///
+/// # Example
+///
+/// ```no_run
+/// use eiga::{search, Client, Tmdb};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct MovieResult {
+///     title: String,
+/// }
+///
+/// let tmdb = Tmdb::new("<token>");
+/// let endpoint = search::Movies::new("Godzilla");
+///
+/// let mut page_iter = tmdb.page(&endpoint).page_concurrent(8).max_results(50);
+/// let first: MovieResult = page_iter.next().unwrap().unwrap();
+///
+/// println!("{} of {:?} results", first.title, page_iter.total_results());
+/// ```
 #[derive(Debug)]
 pub struct PageIter<'a, C, E, T>
 where
     C: ?Sized,
 {
-    state: PageIterState<'a, C, E>,
+    pages: Pages<'a, C, E, T>,
     results: VecDeque<T>,
+    current_page: Option<u16>,
+    offset: usize,
+    skip: usize,
+    max_results: Option<usize>,
+    results_yielded: usize,
 }
 
+/// A pageable results iterator that owns its client and endpoint.
+///
+/// Unlike a [`PageIter`] from [`Client::page`], it doesn't borrow anything,
+/// so it can be stored in structs and moved across threads. Construct one
+/// with [`PageIter::owned`] or [`PageIter::resume_owned`].
+pub type OwnedPageIter<C, E, T> = PageIter<'static, C, E, T>;
+
 impl<'a, C, E, D> PageIter<'a, C, E, D>
 where
     C: Client,
@@ -99,12 +700,160 @@ where
     pub(crate) fn new(
         client: &'a C,
         endpoint: &'a E,
+    ) -> PageIter<'a, C, E, D> {
+        PageIter::with_handles(
+            Handle::Borrowed(client),
+            Handle::Borrowed(endpoint),
+        )
+    }
+
+    /// Constructs a new `PageIter` that owns the given client and endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::thread;
+    ///
+    /// use eiga::{search, OwnedPageIter, PageIter, Tmdb};
+    ///
+    /// let tmdb = Tmdb::new("<token>");
+    /// let endpoint = search::Movies::new("Godzilla");
+    /// let page_iter: OwnedPageIter<_, _, serde_json::Value> =
+    ///     PageIter::owned(tmdb, endpoint);
+    ///
+    /// let handle = thread::spawn(move || page_iter.count());
+    /// println!("{} results", handle.join().unwrap());
+    /// ```
+    pub fn owned(client: C, endpoint: E) -> PageIter<'a, C, E, D> {
+        PageIter::with_handles(
+            Handle::Owned(Box::new(client)),
+            Handle::Owned(Box::new(endpoint)),
+        )
+    }
+
+    fn with_handles(
+        client: Handle<'a, C>,
+        endpoint: Handle<'a, E>,
     ) -> PageIter<'a, C, E, D> {
         PageIter {
-            state: PageIterState::new(client, endpoint),
+            pages: Pages::new(client, endpoint),
             results: VecDeque::new(),
+            current_page: None,
+            offset: 0,
+            skip: 0,
+            max_results: None,
+            results_yielded: 0,
         }
     }
+
+    /// Fetches up to `concurrency` pages in parallel.
+    ///
+    /// See [`Pages::page_concurrent`].
+    pub fn page_concurrent(mut self, concurrency: usize) -> Self
+    where
+        C: Sync,
+        E: Sync,
+        D: Send,
+    {
+        self.pages = self.pages.page_concurrent(concurrency);
+
+        self
+    }
+
+    /// Stops after fetching `max_pages` pages.
+    pub fn max_pages(mut self, max_pages: u16) -> Self {
+        self.pages = self.pages.max_pages(max_pages);
+
+        self
+    }
+
+    /// Stops after yielding `max_results` results.
+    ///
+    /// Pages that would only hold results past the limit aren't fetched.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+
+        self
+    }
+
+    /// Returns the number of results for the request.
+    ///
+    /// This is `None` until the first page is fetched, or if the endpoint
+    /// doesn't respond with it.
+    pub fn total_results(&self) -> Option<usize> {
+        self.pages.total_results()
+    }
+
+    /// Returns the number of pages for the request.
+    ///
+    /// This is `None` until the first page is fetched.
+    pub fn total_pages(&self) -> Option<u16> {
+        self.pages.total_pages()
+    }
+
+    /// Returns `true` if there are more pages than TMDB responds with.
+    ///
+    /// See [`Pages::is_truncated`].
+    pub fn is_truncated(&self) -> bool {
+        self.pages.is_truncated()
+    }
+
+    /// Returns a cursor at the next result, or `None` if there are no
+    /// results left.
+    ///
+    /// The cursor doesn't include the limits set with
+    /// [`PageIter::max_pages`] and [`PageIter::max_results`].
+    pub fn cursor(&self) -> Option<Cursor> {
+        match self.current_page {
+            Some(page) if !self.results.is_empty() => {
+                Some(Cursor::new(&*self.pages.endpoint, page, self.offset))
+            }
+            _ => {
+                let mut cursor = self.pages.cursor()?;
+                cursor.offset = self.skip;
+
+                Some(cursor)
+            }
+        }
+    }
+
+    /// Returns an iterator over the remaining pages instead of the results.
+    ///
+    /// Results of the current page that haven't been yielded yet are
+    /// dropped, so call this before iterating over the results.
+    pub fn pages(self) -> Pages<'a, C, E, D> {
+        self.pages
+    }
+}
+
+impl<'a, C, D> PageIter<'a, C, Cursor, D>
+where
+    C: Client,
+    D: DeserializeOwned,
+{
+    /// Constructs a new `PageIter` that resumes iteration at `cursor`.
+    pub fn resume(
+        client: &'a C,
+        cursor: &'a Cursor,
+    ) -> PageIter<'a, C, Cursor, D> {
+        let mut page_iter = PageIter::new(client, cursor);
+        page_iter.skip = cursor.offset;
+
+        page_iter
+    }
+
+    /// Constructs a new `PageIter` that owns the given client and cursor and
+    /// resumes iteration at `cursor`.
+    pub fn resume_owned(
+        client: C,
+        cursor: Cursor,
+    ) -> PageIter<'a, C, Cursor, D> {
+        let skip = cursor.offset;
+        let mut page_iter = PageIter::owned(client, cursor);
+        page_iter.skip = skip;
+
+        page_iter
+    }
 }
 
 impl<'a, C, E, D> Iterator for PageIter<'a, C, E, D>
@@ -116,23 +865,41 @@ where
     type Item = Result<D, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let page = self.state.next_page?;
-
-        if self.results.is_empty() {
-            let response: Page<D> = match self.state.client.send(&self.state) {
-                Ok(response) => response,
-                Err(err) => return Some(Err(err)),
-            };
+        let results_left = match self.max_results {
+            Some(max_results) => {
+                max_results - self.results_yielded.min(max_results)
+            }
+            None => usize::MAX,
+        };
+        if results_left == 0 {
+            return None;
+        }
 
-            self.state.next_page = if page < response.total_pages {
-                Some(page + 1)
-            } else {
-                None
+        while self.results.is_empty() {
+            // Only fetch as many pages as it takes to reach the limit.
+            let max_batch = match self.pages.page_size {
+                Some(page_size) if page_size > 0 => {
+                    let pages_left = results_left.div_ceil(page_size);
+                    u16::try_from(pages_left).unwrap_or(u16::MAX)
+                }
+                _ => u16::MAX,
             };
 
-            self.results.extend(response.results)
+            match self.pages.next_page(max_batch)? {
+                Ok(page) => {
+                    // Skip the results a resumed cursor already yielded.
+                    self.offset = self.skip.min(page.results.len());
+                    self.skip = 0;
+                    self.current_page = Some(page.page);
+                    self.results
+                        .extend(page.results.into_iter().skip(self.offset));
+                }
+                Err(err) => return Some(Err(err)),
+            }
         }
 
+        self.offset += 1;
+        self.results_yielded += 1;
         self.results.pop_front().map(Ok)
     }
 }
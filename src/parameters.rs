@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use url::Url;
 
-use crate::{Country, Language};
+use crate::{account, v4, Country, Language};
 
 /// A query string parameter value.
 #[derive(Debug)]
@@ -33,18 +33,36 @@ impl<'a> From<&Language> for Value<'a> {
     }
 }
 
+impl<'a> From<&account::SortBy> for Value<'a> {
+    fn from(sort_by: &account::SortBy) -> Self {
+        Value(format!("{}", sort_by).into())
+    }
+}
+
 impl<'a> From<&'a str> for Value<'a> {
     fn from(s: &'a str) -> Self {
         Value(s.into())
     }
 }
 
+impl<'a> From<String> for Value<'a> {
+    fn from(s: String) -> Self {
+        Value(s.into())
+    }
+}
+
 impl<'a> From<u16> for Value<'a> {
     fn from(u: u16) -> Self {
         Value(u.to_string().into())
     }
 }
 
+impl<'a> From<&v4::list::SortBy> for Value<'a> {
+    fn from(sort_by: &v4::list::SortBy) -> Self {
+        Value(sort_by.as_str().into())
+    }
+}
+
 /// A helper type for collecting query string parameters.
 ///
 /// # Example
@@ -122,8 +140,32 @@ impl<'a> Parameters<'a> {
         }
     }
 
+    /// Returns an iterator over the collected parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eiga::Parameters;
+    ///
+    /// let mut parameters = Parameters::new();
+    /// parameters.push("language", Some("en-US"));
+    ///
+    /// assert_eq!(
+    ///     vec![("language", "en-US")],
+    ///     parameters.iter().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (*k, v.as_str()))
+    }
+
     /// Appends the collected parameters to the given URL.
     pub fn append_to_url(&self, url: &mut Url) {
+        // An empty query would leave a dangling `?` on the URL.
+        if self.0.is_empty() {
+            return;
+        }
+
         let mut pairs = url.query_pairs_mut();
         pairs.extend_pairs(self.0.iter().map(|(k, v)| (k, v.as_str())));
     }
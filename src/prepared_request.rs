@@ -0,0 +1,167 @@
+use std::fmt;
+
+use http::Method;
+use url::Url;
+
+use crate::body::redact;
+use crate::Body;
+
+/// The value that replaces the token in inspected `Authorization` headers.
+const REDACTED_AUTHORIZATION: &str = "Bearer <redacted>";
+
+/// The body fields that are redacted from inspected bodies.
+const REDACTED_BODY_FIELDS: [(&str, &str); 1] = [("password", "<redacted>")];
+
+/// A fully resolved request, ready to send.
+///
+/// [`Tmdb::prepare`] returns the request that [`Tmdb`] would send for an
+/// endpoint without sending it. This is useful for logging, signing, or
+/// sending requests with another HTTP client.
+///
+/// The token in the `Authorization` header is redacted from
+/// [`PreparedRequest::headers`], [`PreparedRequest::to_curl`], and the
+/// `Debug` output, and so is the `password` field of JSON bodies, like the
+/// body of [`authentication::CreateSessionWithLogin`]. The URL isn't
+/// redacted, so it includes the session ID for endpoints that require a
+/// session. [`PreparedRequest::body`] returns the body as it's sent, without
+/// redactions.
+///
+/// # Example
+///
+/// ```
+/// use eiga::{search, Tmdb};
+///
+/// let tmdb = Tmdb::new("<token>");
+/// let endpoint = search::Movies::new("Tampopo").year(1985);
+///
+/// let request = tmdb.prepare(&endpoint).unwrap();
+///
+/// assert_eq!("GET", request.method().as_str());
+/// assert_eq!(
+///     "https://api.themoviedb.org/3/search/movie?query=Tampopo&year=1985",
+///     request.url().as_str()
+/// );
+/// assert_eq!(
+///     format!(
+///         "curl -X GET 'https://api.themoviedb.org/3/search/movie?query=Tampopo&year=1985' \
+///          -H 'authorization: Bearer <redacted>' \
+///          -H 'user-agent: eiga/{}'",
+///         env!("CARGO_PKG_VERSION")
+///     ),
+///     request.to_curl()
+/// );
+/// ```
+///
+/// [`Tmdb`]: crate::Tmdb
+/// [`Tmdb::prepare`]: crate::Tmdb::prepare
+/// [`authentication::CreateSessionWithLogin`]:
+/// crate::authentication::CreateSessionWithLogin
+pub struct PreparedRequest {
+    method: Method,
+    url: Url,
+    authorization: String,
+    user_agent: Option<String>,
+    body: Option<Body>,
+}
+
+impl PreparedRequest {
+    /// Constructs a new `PreparedRequest`.
+    pub(crate) fn new(
+        method: Method,
+        url: Url,
+        authorization: String,
+        user_agent: Option<String>,
+        body: Option<Body>,
+    ) -> PreparedRequest {
+        PreparedRequest {
+            method,
+            url,
+            authorization,
+            user_agent,
+            body,
+        }
+    }
+
+    /// Returns the HTTP method of the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns the URL of the request, with the query string parameters
+    /// appended.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the headers of the request, with the token redacted.
+    ///
+    /// The `User-Agent` header is only included for the default backend,
+    /// since other backends set their own.
+    pub fn headers(&self) -> Vec<(&str, &str)> {
+        let mut headers = vec![("authorization", REDACTED_AUTHORIZATION)];
+        if let Some(user_agent) = &self.user_agent {
+            headers.push(("user-agent", user_agent));
+        }
+        if let Some(body) = &self.body {
+            headers.push(("content-type", body.content_type()));
+        }
+
+        headers
+    }
+
+    /// Returns the body of the request, if it has one.
+    ///
+    /// Unlike the rest of the inspected request, the body isn't redacted.
+    pub fn body(&self) -> Option<&Body> {
+        self.body.as_ref()
+    }
+
+    /// Returns the `Authorization` header, with the token.
+    pub(crate) fn authorization(&self) -> &str {
+        &self.authorization
+    }
+
+    /// Renders the request as a `curl` command, with the token and any
+    /// password redacted.
+    pub fn to_curl(&self) -> String {
+        let mut command = format!(
+            "curl -X {} {}",
+            self.method,
+            shell_quote(self.url.as_str())
+        );
+        for (name, value) in self.headers() {
+            command.push_str(" -H ");
+            command.push_str(&shell_quote(&format!("{}: {}", name, value)));
+        }
+        if let Some(body) = &self.body {
+            command.push_str(" --data-raw ");
+            command.push_str(&shell_quote(&redact(
+                body.as_bytes(),
+                &REDACTED_BODY_FIELDS,
+            )));
+        }
+
+        command
+    }
+}
+
+impl fmt::Debug for PreparedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreparedRequest")
+            .field("method", &self.method)
+            .field("url", &self.url.as_str())
+            .field("headers", &self.headers())
+            .field(
+                "body",
+                &self.body.as_ref().map(|body| {
+                    redact(body.as_bytes(), &REDACTED_BODY_FIELDS)
+                }),
+            )
+            .finish()
+    }
+}
+
+/// Quotes `s` for a POSIX shell.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
@@ -0,0 +1,317 @@
+use std::cmp::Reverse;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::body::redact;
+use crate::testing::Request;
+use crate::tmdb::{check_response, deserialize};
+use crate::{
+    ApiVersion, Client, Endpoint, Error, HttpResponse, PageIter, Pageable,
+    Tmdb,
+};
+
+/// A request saved to a cassette.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    api_version: ApiVersion,
+    path: String,
+    parameters: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+/// The request body fields that hold credentials, with their placeholders.
+const BODY_CREDENTIAL_FIELDS: [(&str, &str); 2] =
+    [("username", "<username>"), ("password", "<password>")];
+
+impl From<&Request> for RecordedRequest {
+    fn from(request: &Request) -> RecordedRequest {
+        // Bodies are scrubbed before they're compared, so requests with
+        // credentials match their recordings when replayed.
+        RecordedRequest {
+            method: request.method().as_str().to_owned(),
+            api_version: request.api_version(),
+            path: request.path().to_owned(),
+            parameters: request.parameters().to_vec(),
+            body: request.body().map(|body| {
+                redact(body, &BODY_CREDENTIAL_FIELDS).into_owned()
+            }),
+        }
+    }
+}
+
+/// The response fields that hold credentials, with their placeholders.
+const CREDENTIAL_FIELDS: [(&str, &str); 4] = [
+    ("session_id", "<session_id>"),
+    ("guest_session_id", "<guest_session_id>"),
+    ("access_token", "<access_token>"),
+    ("request_token", "<request_token>"),
+];
+
+/// A response saved to a cassette.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl From<&HttpResponse> for RecordedResponse {
+    fn from(response: &HttpResponse) -> RecordedResponse {
+        RecordedResponse {
+            status: response.status(),
+            headers: response.headers().to_vec(),
+            body: String::from_utf8_lossy(response.body()).into_owned(),
+        }
+    }
+}
+
+impl RecordedResponse {
+    /// Returns the credentials in the body of this response, like the
+    /// session IDs and tokens that authentication endpoints respond with,
+    /// each paired with its placeholder.
+    fn credentials(&self) -> Vec<(String, &'static str)> {
+        let body: serde_json::Value = match serde_json::from_str(&self.body) {
+            Ok(body) => body,
+            Err(_) => return Vec::new(),
+        };
+
+        CREDENTIAL_FIELDS
+            .iter()
+            .filter_map(|(field, placeholder)| {
+                let credential = body.get(field)?.as_str()?;
+                Some((credential.to_owned(), *placeholder))
+            })
+            .collect()
+    }
+
+    /// Returns an `HttpResponse` with the status, headers, and body of this
+    /// response.
+    fn to_response(&self) -> HttpResponse {
+        HttpResponse::new(
+            self.status,
+            self.headers.clone(),
+            self.body.clone().into_bytes(),
+        )
+    }
+}
+
+/// A request and the response to it.
+#[derive(Debug, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+    #[serde(skip)]
+    replayed: bool,
+}
+
+/// The contents of a cassette file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Tape {
+    interactions: Vec<Interaction>,
+}
+
+/// A client that records requests and responses to a file and replays them
+/// later.
+///
+/// In record mode, a cassette sends requests with a [`Tmdb`] and saves each
+/// request (method, path, parameters, and body) along with its response
+/// (status, headers, and body). [`Cassette::save`] writes them to the
+/// cassette file as JSON. Credentials are replaced with placeholders
+/// wherever they appear: the token, access token, and session ID of the
+/// [`Tmdb`] become `<token>`, `<access_token>`, and `<session_id>` or
+/// `<guest_session_id>`, and the session IDs, request tokens, and access
+/// tokens that authentication endpoints respond with become placeholders
+/// named after their fields. The usernames and passwords in request bodies,
+/// like the body of [`authentication::CreateSessionWithLogin`], become
+/// `<username>` and `<password>`. Requests that use those credentials still
+/// match when replayed with the placeholders.
+///
+/// In replay mode, a cassette responds to each request with the first
+/// recorded response to an equal request that it hasn't replayed yet,
+/// without network access. It panics if there's no such response.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::env;
+///
+/// use eiga::testing::Cassette;
+/// use eiga::{movie, Client, Tmdb};
+/// use serde_json::Value;
+///
+/// let path = "tests/cassettes/tokyo_drifter.json";
+/// let cassette = match env::var("TMDB_TOKEN") {
+///     Ok(token) => Cassette::record(Tmdb::new(token), path),
+///     Err(_) => Cassette::replay(path).unwrap(),
+/// };
+///
+/// let details: Value = cassette.send(&movie::Details::new(45706)).unwrap();
+/// assert_eq!("Tokyo Drifter", details["title"]);
+///
+/// cassette.save().unwrap();
+/// ```
+///
+/// [`authentication::CreateSessionWithLogin`]:
+/// crate::authentication::CreateSessionWithLogin
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    // The client that sends requests in record mode, or `None` in replay
+    // mode.
+    tmdb: Option<Tmdb>,
+    tape: Mutex<Tape>,
+}
+
+impl Cassette {
+    /// Constructs a new `Cassette` that sends requests with `tmdb` and
+    /// records them to the file at `path`.
+    ///
+    /// Nothing is written until [`Cassette::save`] is called.
+    pub fn record<P>(tmdb: Tmdb, path: P) -> Cassette
+    where
+        P: AsRef<Path>,
+    {
+        Cassette {
+            path: path.as_ref().to_owned(),
+            tmdb: Some(tmdb),
+            tape: Mutex::new(Tape::default()),
+        }
+    }
+
+    /// Constructs a new `Cassette` that replays the file at `path`.
+    pub fn replay<P>(path: P) -> Result<Cassette, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let contents = fs::read_to_string(&path).map_err(Error::Io)?;
+        let tape = serde_json::from_str(&contents).map_err(io::Error::from)?;
+
+        Ok(Cassette {
+            path: path.as_ref().to_owned(),
+            tmdb: None,
+            tape: Mutex::new(tape),
+        })
+    }
+
+    /// Writes the recorded requests and responses to the cassette file.
+    ///
+    /// The file and its parent directories are created if they don't exist.
+    /// This does nothing in replay mode.
+    pub fn save(&self) -> Result<(), Error> {
+        let tmdb = match &self.tmdb {
+            Some(tmdb) => tmdb,
+            None => return Ok(()),
+        };
+
+        let tape = self.lock();
+        let mut contents = serde_json::to_string_pretty(&*tape)?;
+
+        let mut credentials: Vec<(String, &str)> = tmdb
+            .credentials()
+            .into_iter()
+            .map(|(credential, placeholder)| {
+                (credential.to_owned(), placeholder)
+            })
+            .collect();
+        for interaction in &tape.interactions {
+            credentials.extend(interaction.response.credentials());
+        }
+        // Replace longer credentials first in case one contains another.
+        credentials.sort_by_key(|(credential, _)| Reverse(credential.len()));
+        for (credential, placeholder) in credentials {
+            // Replacing an empty string would insert the placeholder
+            // between every character.
+            if !credential.is_empty() {
+                contents = contents.replace(&credential, placeholder);
+            }
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        fs::write(&self.path, contents).map_err(Error::Io)
+    }
+
+    /// Returns the response to the given endpoint.
+    fn call<E>(&self, endpoint: &E) -> Result<HttpResponse, Error>
+    where
+        E: Endpoint,
+    {
+        endpoint.validate()?;
+        let request = RecordedRequest::from(&Request::new(endpoint)?);
+
+        let response = match &self.tmdb {
+            Some(tmdb) => {
+                let response = tmdb.execute(endpoint)?;
+
+                self.lock().interactions.push(Interaction {
+                    request,
+                    response: RecordedResponse::from(&response),
+                    replayed: true,
+                });
+
+                response
+            }
+            None => {
+                let mut tape = self.lock();
+                let interaction = tape
+                    .interactions
+                    .iter_mut()
+                    .find(|interaction| {
+                        !interaction.replayed && interaction.request == request
+                    })
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "cassette {} has no response for {:#?}",
+                            self.path.display(),
+                            request
+                        )
+                    });
+                interaction.replayed = true;
+
+                interaction.response.to_response()
+            }
+        };
+
+        check_response(endpoint, response)
+    }
+
+    /// Locks the tape, ignoring poisoning.
+    fn lock(&self) -> MutexGuard<'_, Tape> {
+        self.tape.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Client for Cassette {
+    fn send<E, D>(&self, endpoint: &E) -> Result<D, Error>
+    where
+        E: Endpoint,
+        D: DeserializeOwned,
+    {
+        let response = self.call(endpoint)?;
+
+        deserialize(response.body())
+    }
+
+    fn ignore<E>(&self, endpoint: &E) -> Result<(), Error>
+    where
+        E: Endpoint,
+    {
+        self.call(endpoint)?;
+
+        Ok(())
+    }
+
+    fn page<'a, E, D>(&'a self, endpoint: &'a E) -> PageIter<'a, Self, E, D>
+    where
+        E: Pageable,
+        D: DeserializeOwned,
+    {
+        PageIter::new(self, endpoint)
+    }
+}
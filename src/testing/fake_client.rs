@@ -0,0 +1,337 @@
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+
+use http::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{ApiVersion, Client, Endpoint, Error, PageIter, Pageable};
+
+/// A canned response for requests with a given method and path.
+///
+/// A stub matches a request if the method and path are equal and the
+/// request has every parameter of the stub. It can have other parameters
+/// too.
+pub struct Stub {
+    method: Method,
+    path: String,
+    parameters: Vec<(String, String)>,
+    status: u16,
+    body: Value,
+    error: Option<Box<dyn Fn() -> Error + Send + Sync>>,
+}
+
+impl Stub {
+    /// Constructs a new `Stub` for requests with the given method and path.
+    ///
+    /// The stub responds with status 200 and a `null` body by default.
+    pub fn new<S>(method: Method, path: S) -> Stub
+    where
+        S: Into<String>,
+    {
+        Stub {
+            method,
+            path: path.into(),
+            parameters: Vec::new(),
+            status: 200,
+            body: Value::Null,
+            error: None,
+        }
+    }
+
+    /// Constructs a new `Stub` for `GET` requests with the given path.
+    pub fn get<S>(path: S) -> Stub
+    where
+        S: Into<String>,
+    {
+        Stub::new(Method::GET, path)
+    }
+
+    /// Constructs a new `Stub` for `POST` requests with the given path.
+    pub fn post<S>(path: S) -> Stub
+    where
+        S: Into<String>,
+    {
+        Stub::new(Method::POST, path)
+    }
+
+    /// Constructs a new `Stub` for `DELETE` requests with the given path.
+    pub fn delete<S>(path: S) -> Stub
+    where
+        S: Into<String>,
+    {
+        Stub::new(Method::DELETE, path)
+    }
+
+    /// Only matches requests with the given query string parameter.
+    pub fn parameter<K, V>(mut self, key: K, value: V) -> Stub
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.parameters.push((key.into(), value.into()));
+
+        self
+    }
+
+    /// Sets the status of the response.
+    ///
+    /// Statuses outside of 200-299 fail with [`Error::Tmdb`], unless the
+    /// endpoint considers them a success. The error message comes from the
+    /// body, like TMDB's error responses.
+    pub fn status(mut self, status: u16) -> Stub {
+        self.status = status;
+
+        self
+    }
+
+    /// Sets the JSON body of the response.
+    pub fn json(mut self, body: Value) -> Stub {
+        self.body = body;
+
+        self
+    }
+
+    /// Fails matching requests with the error returned by `error`.
+    ///
+    /// This is useful for errors that TMDB doesn't respond with, like
+    /// transport errors.
+    pub fn error<F>(mut self, error: F) -> Stub
+    where
+        F: Fn() -> Error + Send + Sync + 'static,
+    {
+        self.error = Some(Box::new(error));
+
+        self
+    }
+
+    /// Returns `true` if this stub matches the given request.
+    fn matches(&self, request: &Request) -> bool {
+        self.method == request.method
+            && self.path == request.path
+            && self
+                .parameters
+                .iter()
+                .all(|parameter| request.parameters.contains(parameter))
+    }
+
+    /// Returns the response body, or an error if the response is an error.
+    fn respond<E>(&self, endpoint: &E) -> Result<Value, Error>
+    where
+        E: Endpoint,
+    {
+        if let Some(error) = &self.error {
+            return Err(error());
+        }
+        if (200..300).contains(&self.status) {
+            return Ok(self.body.clone());
+        }
+
+        let status_code = self.body["status_code"]
+            .as_u64()
+            .and_then(|status_code| u32::try_from(status_code).ok());
+        if endpoint.is_success(self.status, status_code) {
+            return Ok(self.body.clone());
+        }
+
+        let message =
+            match (&self.body["status_message"], &self.body["errors"]) {
+                (Value::String(message), _) => message.clone(),
+                (_, Value::Array(errors)) => errors
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => String::new(),
+            };
+
+        Err(Error::Tmdb {
+            code: self.status,
+            message,
+        })
+    }
+}
+
+impl fmt::Debug for Stub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stub")
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .field("parameters", &self.parameters)
+            .field("status", &self.status)
+            .field("body", &self.body)
+            .field("error", &self.error.is_some())
+            .finish()
+    }
+}
+
+/// A request received by a [`FakeClient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    method: Method,
+    api_version: ApiVersion,
+    path: String,
+    parameters: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+}
+
+impl Request {
+    /// Constructs a new `Request` from the given endpoint.
+    pub(crate) fn new<E>(endpoint: &E) -> Result<Request, Error>
+    where
+        E: Endpoint,
+    {
+        let parameters = endpoint
+            .parameters()
+            .iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+
+        Ok(Request {
+            method: endpoint.method(),
+            api_version: endpoint.api_version(),
+            path: endpoint.path().into_owned(),
+            parameters,
+            body: endpoint.body()?.map(|body| body.as_bytes().to_vec()),
+        })
+    }
+
+    /// Returns the HTTP method of the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns the API version of the request.
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Returns the path of the request.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the query string parameters of the request.
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Returns the value of the given query string parameter, if the request
+    /// has it.
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the body of the request, if it has one.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+}
+
+/// A fake client that responds with canned JSON.
+///
+/// Each request is answered by the stub that matches it. If more than one
+/// stub matches, the one with the most parameters wins, and ties go to the
+/// stub registered last. Requests that no stub matches fail with a 404
+/// [`Error::Tmdb`].
+///
+/// Like [`Tmdb`], the client validates endpoints before "sending" them.
+/// Session IDs aren't attached to requests.
+///
+/// [`Tmdb`]: crate::Tmdb
+#[derive(Debug, Default)]
+pub struct FakeClient {
+    stubs: Mutex<Vec<Stub>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl FakeClient {
+    /// Constructs a new `FakeClient` without any stubs.
+    pub fn new() -> FakeClient {
+        FakeClient::default()
+    }
+
+    /// Registers a stub.
+    pub fn stub(&self, stub: Stub) {
+        lock(&self.stubs).push(stub);
+    }
+
+    /// Returns the requests the client has received, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.requests).clone()
+    }
+
+    /// Forgets the requests the client has received.
+    pub fn clear_requests(&self) {
+        lock(&self.requests).clear();
+    }
+
+    /// Records the request to the given endpoint and returns the response
+    /// body of the matching stub.
+    fn call<E>(&self, endpoint: &E) -> Result<Value, Error>
+    where
+        E: Endpoint,
+    {
+        endpoint.validate()?;
+
+        let request = Request::new(endpoint)?;
+        lock(&self.requests).push(request.clone());
+
+        let stubs = lock(&self.stubs);
+        let stub = stubs
+            .iter()
+            .filter(|stub| stub.matches(&request))
+            .max_by_key(|stub| stub.parameters.len());
+
+        match stub {
+            Some(stub) => stub.respond(endpoint),
+            None => Err(Error::Tmdb {
+                code: 404,
+                message: format!(
+                    "no stub matches {} {}",
+                    request.method, request.path
+                ),
+            }),
+        }
+    }
+}
+
+/// Locks the given mutex, ignoring poisoning.
+///
+/// A panicking test thread shouldn't hide the requests from other threads.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl Client for FakeClient {
+    fn send<E, D>(&self, endpoint: &E) -> Result<D, Error>
+    where
+        E: Endpoint,
+        D: DeserializeOwned,
+    {
+        let body = self.call(endpoint)?;
+
+        serde_json::from_value(body)
+            .map_err(|err| Error::Deserialize(err.into()))
+    }
+
+    fn ignore<E>(&self, endpoint: &E) -> Result<(), Error>
+    where
+        E: Endpoint,
+    {
+        self.call(endpoint)?;
+
+        Ok(())
+    }
+
+    fn page<'a, E, D>(&'a self, endpoint: &'a E) -> PageIter<'a, Self, E, D>
+    where
+        E: Pageable,
+        D: DeserializeOwned,
+    {
+        PageIter::new(self, endpoint)
+    }
+}
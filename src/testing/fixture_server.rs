@@ -0,0 +1,456 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::validation::MAX_PAGE;
+
+/// The number of results in a page served from an array fixture.
+const PAGE_SIZE: usize = 20;
+
+/// A local HTTP server that stands in for the TMDB API.
+///
+/// The server responds to requests with JSON fixtures from a directory laid
+/// out by request path. The fixture for `GET /3/movie/45706` is
+/// `<dir>/3/movie/45706.json`. Fixtures for other methods have the method in
+/// their name, so the fixture for `POST /3/movie/45706/rating` is
+/// `<dir>/3/movie/45706/rating.post.json`.
+///
+/// - A fixture that's a JSON array is served as pages of 20 results, like
+///   TMDB's pageable endpoints. The `page` parameter picks the page, and
+///   pages outside of 1-500 are rejected with a 422.
+/// - A fixture that's a TMDB error body, with `"success": false` and a
+///   `status_code`, is served with the HTTP status TMDB uses for that code,
+///   for example 404 for code 34, 429 for code 25, and 500 for code 11.
+/// - Any other fixture is served as is.
+///
+/// Requests without a fixture get TMDB's 404 body. If a token is set,
+/// requests without it as a bearer token get TMDB's 401 body. If a rate
+/// limit is set, requests over the limit get TMDB's 429 body.
+///
+/// The same server is available as the `eiga-mock-server` binary with the
+/// `mock-server` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use eiga::testing::FixtureServer;
+/// use eiga::{movie, Client, Tmdb};
+/// use serde_json::Value;
+///
+/// let server = FixtureServer::new("tests/fixtures")
+///     .token("<token>")
+///     .start("127.0.0.1:0")
+///     .unwrap();
+/// let tmdb = Tmdb::builder("<token>")
+///     .base_url(server.base_url())
+///     .build()
+///     .unwrap();
+///
+/// let details: Value = tmdb.send(&movie::Details::new(45706)).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixtureServer {
+    dir: PathBuf,
+    token: Option<String>,
+    rate_limit: Option<u32>,
+}
+
+impl FixtureServer {
+    /// Constructs a new `FixtureServer` that serves fixtures from `dir`.
+    pub fn new<P>(dir: P) -> FixtureServer
+    where
+        P: AsRef<Path>,
+    {
+        FixtureServer {
+            dir: dir.as_ref().to_owned(),
+            token: None,
+            rate_limit: None,
+        }
+    }
+
+    /// Only accepts requests with the given bearer token.
+    pub fn token<S>(mut self, token: S) -> FixtureServer
+    where
+        S: Into<String>,
+    {
+        self.token = Some(token.into());
+
+        self
+    }
+
+    /// Rejects requests past `requests_per_second` each second with a 429.
+    pub fn rate_limit(mut self, requests_per_second: u32) -> FixtureServer {
+        self.rate_limit = Some(requests_per_second);
+
+        self
+    }
+
+    /// Starts serving on `addr` in a background thread.
+    ///
+    /// Use port 0 to serve on any free port. The server stops when the
+    /// returned [`RunningFixtureServer`] is dropped.
+    pub fn start<A>(self, addr: A) -> io::Result<RunningFixtureServer>
+    where
+        A: ToSocketAddrs,
+    {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handler = Handler::new(self);
+        let thread = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || handler.accept(&listener, &stop))
+        };
+
+        Ok(RunningFixtureServer {
+            addr,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Serves on `listener` until accepting a connection fails.
+    pub fn serve(self, listener: &TcpListener) -> io::Result<()> {
+        Handler::new(self).accept(listener, &AtomicBool::new(false))
+    }
+}
+
+/// A [`FixtureServer`] serving in a background thread.
+#[derive(Debug)]
+pub struct RunningFixtureServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl RunningFixtureServer {
+    /// Returns the address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the base URL to pass to `TmdbBuilder::base_url`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/3/", self.addr)
+    }
+}
+
+impl Drop for RunningFixtureServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        // Wake up the thread blocked on `accept` so it sees `stop`.
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A parsed HTTP request.
+struct HttpRequest {
+    method: String,
+    url: Url,
+    authorization: Option<String>,
+}
+
+impl HttpRequest {
+    /// Reads a request from the given stream.
+    fn read(stream: &TcpStream) -> io::Result<HttpRequest> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => (method.to_owned(), target),
+            _ => return Err(invalid_data("malformed request line")),
+        };
+        let url = Url::parse("http://localhost")
+            .and_then(|base| base.join(target))
+            .map_err(|_| invalid_data("malformed request target"))?;
+
+        let mut authorization = None;
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                let value = value.trim();
+                if name.eq_ignore_ascii_case("authorization") {
+                    authorization = Some(value.to_owned());
+                } else if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.parse().map_err(|_| {
+                        invalid_data("malformed content length")
+                    })?;
+                }
+            }
+        }
+
+        // The body isn't used, but it has to be read before responding.
+        io::copy(&mut reader.take(content_length), &mut io::sink())?;
+
+        Ok(HttpRequest {
+            method,
+            url,
+            authorization,
+        })
+    }
+
+    /// Returns the value of the given query string parameter.
+    fn parameter(&self, key: &str) -> Option<String> {
+        self.url
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.into_owned())
+    }
+}
+
+/// Returns an `InvalidData` I/O error with the given message.
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Responds to requests for a `FixtureServer`.
+#[derive(Clone)]
+struct Handler {
+    server: Arc<FixtureServer>,
+    window: Arc<Mutex<(Instant, u32)>>,
+}
+
+impl Handler {
+    fn new(server: FixtureServer) -> Handler {
+        Handler {
+            server: Arc::new(server),
+            window: Arc::new(Mutex::new((Instant::now(), 0))),
+        }
+    }
+
+    /// Accepts connections until `stop` is set, handling each connection in
+    /// its own thread.
+    fn accept(
+        &self,
+        listener: &TcpListener,
+        stop: &AtomicBool,
+    ) -> io::Result<()> {
+        for stream in listener.incoming() {
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let handler = self.clone();
+            let stream = stream?;
+            thread::spawn(move || {
+                // A client that hangs up early isn't the server's problem.
+                let _ = handler.handle(stream);
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reads a request from `stream` and writes the response to it.
+    fn handle(&self, mut stream: TcpStream) -> io::Result<()> {
+        let (status, body) = match HttpRequest::read(&stream) {
+            Ok(request) => self.respond(&request),
+            Err(_) => (400, Value::Null),
+        };
+        let body = body.to_string();
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\n\
+             Content-Type: application/json;charset=utf-8\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            status,
+            reason_phrase(status),
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+
+    /// Returns the status and body of the response to `request`.
+    fn respond(&self, request: &HttpRequest) -> (u16, Value) {
+        if let Some(token) = &self.server.token {
+            let expected = format!("Bearer {}", token);
+            if request.authorization.as_deref() != Some(expected.as_str()) {
+                return tmdb_error(
+                    7,
+                    "Invalid API key: You must be granted a valid key.",
+                );
+            }
+        }
+
+        if let Some(rate_limit) = self.server.rate_limit {
+            if !self.allow(rate_limit) {
+                let message = format!(
+                    "Your request count (#) is over the allowed limit of \
+                     ({}).",
+                    rate_limit
+                );
+                return tmdb_error(25, &message);
+            }
+        }
+
+        let fixture = match self.fixture(request) {
+            Some(fixture) => fixture,
+            None => {
+                return tmdb_error(
+                    34,
+                    "The resource you requested could not be found.",
+                )
+            }
+        };
+
+        match fixture {
+            Value::Array(results) => page(results, request),
+            Value::Object(ref body)
+                if body.get("success") == Some(&false.into()) =>
+            {
+                let status_code = body
+                    .get("status_code")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(0);
+                (http_status(status_code), fixture)
+            }
+            fixture => (200, fixture),
+        }
+    }
+
+    /// Returns `true` if another request fits in the current one second
+    /// window.
+    fn allow(&self, rate_limit: u32) -> bool {
+        let mut window =
+            self.window.lock().unwrap_or_else(|err| err.into_inner());
+        if window.0.elapsed() >= Duration::from_secs(1) {
+            *window = (Instant::now(), 0);
+        }
+        window.1 += 1;
+
+        window.1 <= rate_limit
+    }
+
+    /// Reads the fixture for `request`, if there is one.
+    fn fixture(&self, request: &HttpRequest) -> Option<Value> {
+        let mut path = self.server.dir.clone();
+        for segment in request.url.path_segments()? {
+            // Don't serve files outside of the fixture directory.
+            if segment.is_empty() || segment == "." || segment == ".." {
+                return None;
+            }
+            path.push(segment);
+        }
+
+        let extension = match request.method.as_str() {
+            "GET" => "json".to_owned(),
+            method => format!("{}.json", method.to_ascii_lowercase()),
+        };
+        let file_name =
+            format!("{}.{}", path.file_name()?.to_str()?, extension);
+        path.set_file_name(file_name);
+
+        let contents = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+}
+
+/// Returns the requested page of `results`.
+fn page(results: Vec<Value>, request: &HttpRequest) -> (u16, Value) {
+    let page = match request.parameter("page") {
+        Some(page) => match page.parse::<u16>() {
+            Ok(page) => page,
+            Err(_) => return unprocessable_entity("page must be an integer"),
+        },
+        None => 1,
+    };
+    if page == 0 {
+        return unprocessable_entity("page must be greater than 0");
+    }
+    if page > MAX_PAGE {
+        return unprocessable_entity(&format!(
+            "page must be less than or equal to {}",
+            MAX_PAGE
+        ));
+    }
+
+    let total_results = results.len();
+    let total_pages = total_results.div_ceil(PAGE_SIZE).max(1);
+    let page_results: Vec<Value> = results
+        .into_iter()
+        .skip((usize::from(page) - 1) * PAGE_SIZE)
+        .take(PAGE_SIZE)
+        .collect();
+
+    (
+        200,
+        json!({
+            "page": page,
+            "results": page_results,
+            "total_pages": total_pages,
+            "total_results": total_results,
+        }),
+    )
+}
+
+/// Returns TMDB's error response for the given status code and message.
+fn tmdb_error(status_code: u64, status_message: &str) -> (u16, Value) {
+    (
+        http_status(status_code),
+        json!({
+            "success": false,
+            "status_code": status_code,
+            "status_message": status_message,
+        }),
+    )
+}
+
+/// Returns TMDB's 422 response for the given message.
+fn unprocessable_entity(message: &str) -> (u16, Value) {
+    (422, json!({ "errors": [message] }))
+}
+
+/// Returns the HTTP status TMDB responds with for the given status code.
+fn http_status(status_code: u64) -> u16 {
+    match status_code {
+        3 | 7 | 14 | 30 | 35 | 36 => 401,
+        6 | 34 | 37 => 404,
+        25 => 429,
+        11 | 15 => 500,
+        43 => 502,
+        9 | 46 => 503,
+        24 => 504,
+        _ => 400,
+    }
+}
+
+/// Returns the reason phrase for the given HTTP status.
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Bad Request",
+    }
+}
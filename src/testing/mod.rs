@@ -0,0 +1,40 @@
+//! Clients for testing code that uses eiga.
+//!
+//! [`FakeClient`] implements [`Client`] without sending any requests. It
+//! responds with canned JSON registered as [`Stub`]s and records every
+//! request it receives so tests can make assertions about them.
+//!
+//! [`Cassette`] records the requests and responses of a [`Tmdb`] to a file
+//! once, and replays them later without network access.
+//!
+//! [`FixtureServer`] is a local HTTP server that stands in for the TMDB API
+//! and serves fixtures from a directory.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use eiga::testing::{FakeClient, Stub};
+//! use eiga::{movie, Client};
+//! use serde_json::{json, Value};
+//!
+//! let client = FakeClient::new();
+//! client.stub(Stub::get("movie/45706").json(json!({ "title": "Tokyo Drifter" })));
+//!
+//! let details: Value = client.send(&movie::Details::new(45706)).unwrap();
+//!
+//! assert_eq!("Tokyo Drifter", details["title"]);
+//! assert_eq!("movie/45706", client.requests()[0].path());
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`Tmdb`]: crate::Tmdb
+
+mod cassette;
+mod fake_client;
+mod fixture_server;
+
+pub use cassette::Cassette;
+pub use fake_client::{FakeClient, Request, Stub};
+pub use fixture_server::{FixtureServer, RunningFixtureServer};
@@ -0,0 +1,162 @@
+use std::cmp;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use http::Method;
+
+use crate::{Error, HttpResponse, HttpStream};
+
+/// The delay before the first retry when the response doesn't say how long
+/// to wait. It doubles with each retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The longest delay between retries.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A response that can be retried.
+pub(crate) trait Retryable {
+    /// Returns the HTTP status of the response.
+    fn status(&self) -> u16;
+
+    /// Returns the value of the given header, if the response has it.
+    fn header(&self, name: &str) -> Option<&str>;
+}
+
+impl Retryable for HttpResponse {
+    fn status(&self) -> u16 {
+        self.status()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.header(name)
+    }
+}
+
+impl Retryable for HttpStream {
+    fn status(&self) -> u16 {
+        self.status()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.header(name)
+    }
+}
+
+/// The retries and rate limit of requests.
+///
+/// Clones of a `Tmdb` share one `Throttle`, so the rate limit applies to all
+/// of them together.
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    max_retries: u32,
+    // The minimum time between requests, or `None` if there's no rate
+    // limit.
+    interval: Option<Duration>,
+    // The earliest time the next request can be sent.
+    next: Mutex<Instant>,
+}
+
+impl Throttle {
+    /// Constructs a new `Throttle` that retries requests up to
+    /// `max_retries` times and sends at most `rate_limit` requests per
+    /// second.
+    pub(crate) fn new(
+        max_retries: u32,
+        rate_limit: Option<u32>,
+    ) -> Result<Throttle, Error> {
+        let interval = match rate_limit {
+            Some(0) => {
+                return Err(Error::Config(
+                    "the rate limit must be at least 1 request per second"
+                        .into(),
+                ))
+            }
+            Some(rate_limit) => Some(Duration::from_secs(1) / rate_limit),
+            None => None,
+        };
+
+        Ok(Throttle {
+            max_retries,
+            interval,
+            next: Mutex::new(Instant::now()),
+        })
+    }
+
+    /// Sends a request with `send`, waiting for the rate limit before each
+    /// attempt, and returns the response.
+    ///
+    /// Requests are retried when TMDB responds with 429 Too Many Requests.
+    /// `GET` requests are also retried on 5xx statuses, since they don't
+    /// change anything. Retries wait for as long as the `Retry-After` header
+    /// says, up to 30 seconds, or back off exponentially without it. The
+    /// last response is returned once the retries run out.
+    pub(crate) fn send<F, R>(
+        &self,
+        method: &Method,
+        mut send: F,
+    ) -> Result<R, Error>
+    where
+        F: FnMut() -> Result<R, Error>,
+        R: Retryable,
+    {
+        let mut retries = 0;
+        loop {
+            self.wait();
+            let response = send()?;
+
+            if retries == self.max_retries
+                || !should_retry(method, response.status())
+            {
+                return Ok(response);
+            }
+
+            let delay = retry_after(&response).unwrap_or_else(|| {
+                INITIAL_BACKOFF
+                    .checked_mul(2u32.saturating_pow(retries))
+                    .map_or(MAX_BACKOFF, |delay| cmp::min(delay, MAX_BACKOFF))
+            });
+            thread::sleep(delay);
+            retries += 1;
+        }
+    }
+
+    /// Waits until the rate limit allows another request.
+    fn wait(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        // Reserve the next slot and release the lock before sleeping, so
+        // that other threads can reserve the slots after it.
+        let now = Instant::now();
+        let slot = {
+            let mut next =
+                self.next.lock().unwrap_or_else(|err| err.into_inner());
+            let slot = cmp::max(*next, now);
+            *next = slot + interval;
+            slot
+        };
+
+        thread::sleep(slot - now);
+    }
+}
+
+/// Returns `true` if a request with the given method should be retried
+/// after a response with the given status.
+fn should_retry(method: &Method, status: u16) -> bool {
+    status == 429 || (*method == Method::GET && (500..600).contains(&status))
+}
+
+/// Returns how long the response asks to wait before retrying, if it says.
+///
+/// Only the number of seconds form of `Retry-After` is supported.
+fn retry_after<R>(response: &R) -> Option<Duration>
+where
+    R: Retryable,
+{
+    let seconds = response.header("retry-after")?.trim().parse().ok()?;
+
+    Some(cmp::min(Duration::from_secs(seconds), MAX_BACKOFF))
+}
@@ -1,19 +1,32 @@
+use std::io;
+#[cfg(feature = "config")]
+use std::path::Path;
+use std::sync::Arc;
+#[cfg(feature = "ureq")]
+use std::time::Duration;
+
+use http::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
-use ureq::{
-    Agent,
-    Error::{Status, Transport},
-    Header, Response,
-};
+#[cfg(feature = "ureq")]
+use ureq::Agent;
 use url::Url;
 
-use crate::{Client, Endpoint, Error, PageIter, Pageable};
+use crate::config::Config;
+use crate::throttle::Throttle;
+#[cfg(feature = "ureq")]
+use crate::ureq_backend::UreqConfig;
+use crate::{
+    ApiVersion, Client, Country, Endpoint, Error, HttpBackend, HttpResponse,
+    ImageSize, ImageUrlBuilder, Language, PageIter, Pageable, PreparedRequest,
+};
 
 const TMDB_BASE_URL: &str = "https://api.themoviedb.org/3/";
 
 /// The TMDB error response body.
 #[derive(Deserialize)]
 struct TmdbError {
+    status_code: Option<u32>,
     status_message: String,
 }
 
@@ -22,118 +35,761 @@ struct UnprocessableEntityError {
     errors: Vec<String>,
 }
 
+/// A session that's attached to requests on behalf of a user.
+#[derive(Clone, Debug)]
+enum Session {
+    User(String),
+    Guest(String),
+}
+
 /// A builder for `Tmdb`.
 #[derive(Debug)]
-pub struct TmdbBuilder<'a> {
+pub struct TmdbBuilder {
     token: String,
-    base_url: Option<&'a str>,
+    base_url: Option<String>,
+    base_url_v4: Option<String>,
+    session: Option<Session>,
+    access_token: Option<String>,
+    defaults: Vec<(&'static str, String)>,
+    max_retries: u32,
+    rate_limit: Option<u32>,
+    backend: Option<Arc<dyn HttpBackend>>,
+    #[cfg(feature = "ureq")]
+    ureq: UreqConfig,
 }
 
-impl<'a> TmdbBuilder<'a> {
+impl TmdbBuilder {
     /// Constructs a new `TmdbBuilder` from the given token.
-    fn new<S>(token: S) -> TmdbBuilder<'a>
+    fn new<S>(token: S) -> TmdbBuilder
     where
         S: Into<String>,
     {
         TmdbBuilder {
             token: token.into(),
             base_url: None,
+            base_url_v4: None,
+            session: None,
+            access_token: None,
+            defaults: Vec::new(),
+            max_retries: 0,
+            rate_limit: None,
+            backend: None,
+            #[cfg(feature = "ureq")]
+            ureq: UreqConfig::default(),
         }
     }
 
+    /// Constructs a new `TmdbBuilder` from `TMDB_*` environment variables.
+    ///
+    /// Each variable sets the builder setting of the same name.
+    ///
+    /// | Variable               | Setting              |
+    /// |------------------------|----------------------|
+    /// | `TMDB_TOKEN`           | The token (required) |
+    /// | `TMDB_BASE_URL`        | `base_url`           |
+    /// | `TMDB_BASE_URL_V4`     | `base_url_v4`        |
+    /// | `TMDB_SESSION_ID`      | `session_id`         |
+    /// | `TMDB_ACCESS_TOKEN`    | `access_token`       |
+    /// | `TMDB_TIMEOUT`         | `timeout`            |
+    /// | `TMDB_TIMEOUT_CONNECT` | `timeout_connect`    |
+    /// | `TMDB_TIMEOUT_READ`    | `timeout_read`       |
+    /// | `TMDB_PROXY`           | `proxy`              |
+    /// | `TMDB_USER_AGENT`      | `user_agent`         |
+    /// | `TMDB_LANGUAGE`        | `language`           |
+    /// | `TMDB_REGION`          | `region`             |
+    /// | `TMDB_INCLUDE_ADULT`   | `include_adult`      |
+    /// | `TMDB_WATCH_REGION`    | `watch_region`       |
+    /// | `TMDB_RETRY`           | `retry`              |
+    /// | `TMDB_RATE_LIMIT`      | `rate_limit`         |
+    ///
+    /// Timeouts are in seconds and can be fractional. Languages and regions
+    /// are codes, like `en` and `US`. The timeouts, proxy, and user agent
+    /// only apply to the default backend.
+    ///
+    /// Returns [`Error::Config`] if the token isn't set or a setting is
+    /// malformed.
+    pub fn from_env() -> Result<TmdbBuilder, Error> {
+        Config::from_env()?.into_builder()
+    }
+
+    /// Constructs a new `TmdbBuilder` from the TOML file at `path` and
+    /// `TMDB_*` environment variables.
+    ///
+    /// The file has the same settings as `TmdbBuilder::from_env`, with
+    /// lowercase names and without the `TMDB_` prefix. Every setting is
+    /// optional, but the token must be set in either the file or the
+    /// environment. Environment variables take precedence over the file.
+    ///
+    /// ```toml
+    /// token = "<token>"
+    /// base_url = "http://localhost:8080/3/"
+    /// timeout = 30
+    /// timeout_connect = 2.5
+    /// language = "en"
+    /// region = "US"
+    /// retry = 3
+    /// rate_limit = 40
+    /// ```
+    ///
+    /// Returns [`Error::Config`] if the file can't be read, if it has
+    /// unknown or malformed settings, or if the token isn't set.
+    #[cfg(feature = "config")]
+    pub fn from_config<P>(path: P) -> Result<TmdbBuilder, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Config::from_file(path.as_ref())?
+            .merge(Config::from_env()?)
+            .into_builder()
+    }
+
     /// Sets the base URL for requests.
-    pub fn base_url(&mut self, base_url: &'a str) -> &mut TmdbBuilder<'a> {
-        self.base_url = Some(base_url);
+    ///
+    /// If the URL's path ends in `/3/`, the base URL for v4 requests is the
+    /// same URL with `/4/` instead. Otherwise, v4 requests use this base URL
+    /// too. Use `TmdbBuilder::base_url_v4` to set it explicitly.
+    pub fn base_url<S>(&mut self, base_url: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.base_url = Some(base_url.into());
+
+        self
+    }
+
+    /// Sets the base URL for v4 requests.
+    pub fn base_url_v4<S>(&mut self, base_url_v4: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.base_url_v4 = Some(base_url_v4.into());
+
+        self
+    }
+
+    /// Sets the session ID that's attached to requests for endpoints that
+    /// require a session.
+    ///
+    /// This replaces any guest session ID set with
+    /// `TmdbBuilder::guest_session_id`.
+    pub fn session_id<S>(&mut self, session_id: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.session = Some(Session::User(session_id.into()));
+
+        self
+    }
+
+    /// Sets the guest session ID that's attached to requests for endpoints
+    /// that require a session.
+    ///
+    /// This replaces any session ID set with `TmdbBuilder::session_id`.
+    pub fn guest_session_id<S>(
+        &mut self,
+        guest_session_id: S,
+    ) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.session = Some(Session::Guest(guest_session_id.into()));
+
+        self
+    }
+
+    /// Sets the user access token that authorizes v4 requests on behalf of
+    /// a user.
+    pub fn access_token<S>(&mut self, access_token: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.access_token = Some(access_token.into());
+
+        self
+    }
+
+    /// Sets the default language of requests.
+    ///
+    /// The default is used for endpoints that have a `language` parameter
+    /// when it isn't set on the endpoint.
+    pub fn language(&mut self, language: Language) -> &mut TmdbBuilder {
+        self.set_default("language", language.to_string())
+    }
+
+    /// Sets the default region of requests.
+    ///
+    /// The default is used for endpoints that have a `region` parameter when
+    /// it isn't set on the endpoint.
+    pub fn region(&mut self, region: Country) -> &mut TmdbBuilder {
+        self.set_default("region", region.to_string())
+    }
+
+    /// Sets whether requests include adult content by default.
+    ///
+    /// The default is used for endpoints that have an `include_adult`
+    /// parameter when it isn't set on the endpoint.
+    pub fn include_adult(&mut self, include_adult: bool) -> &mut TmdbBuilder {
+        self.set_default("include_adult", include_adult.to_string())
+    }
+
+    /// Sets the default watch region of requests.
+    ///
+    /// The default is used for endpoints that have a `watch_region`
+    /// parameter when it isn't set on the endpoint.
+    pub fn watch_region(&mut self, watch_region: Country) -> &mut TmdbBuilder {
+        self.set_default("watch_region", watch_region.to_string())
+    }
+
+    /// Sets the default value of the given parameter, replacing any value
+    /// set before.
+    fn set_default(
+        &mut self,
+        name: &'static str,
+        value: String,
+    ) -> &mut TmdbBuilder {
+        match self.defaults.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, default)) => *default = value,
+            None => self.defaults.push((name, value)),
+        }
+
+        self
+    }
+
+    /// Sets the number of times a request is retried when TMDB is rate
+    /// limiting or has a server error.
+    ///
+    /// Requests are retried on 429 Too Many Requests, and `GET` requests and
+    /// image downloads are also retried on 5xx statuses. Retries wait for as
+    /// long as the `Retry-After` header says, up to 30 seconds, or back off
+    /// exponentially from half a second without it. Requests aren't retried
+    /// by default.
+    pub fn retry(&mut self, max_retries: u32) -> &mut TmdbBuilder {
+        self.max_retries = max_retries;
+
+        self
+    }
+
+    /// Sets the maximum number of requests sent per second, including
+    /// retries and image downloads.
+    ///
+    /// Requests are spaced evenly and wait for their turn. Clones of the
+    /// `Tmdb` share the limit. There's no limit by default.
+    ///
+    /// Building fails with [`Error::Config`] if `requests_per_second` is 0.
+    pub fn rate_limit(
+        &mut self,
+        requests_per_second: u32,
+    ) -> &mut TmdbBuilder {
+        self.rate_limit = Some(requests_per_second);
+
+        self
+    }
+
+    /// Sets the HTTP backend that sends requests.
+    ///
+    /// The default backend is [`UreqBackend`], which requires the `ureq`
+    /// feature. The timeouts, proxy, root certificates, user agent, and
+    /// agent of this builder only apply to the default backend.
+    ///
+    /// [`UreqBackend`]: crate::UreqBackend
+    pub fn backend<B>(&mut self, backend: B) -> &mut TmdbBuilder
+    where
+        B: HttpBackend + 'static,
+    {
+        self.backend = Some(Arc::new(backend));
+
+        self
+    }
+
+    /// Sets the timeout for whole requests, from connecting to reading the
+    /// end of the response.
+    ///
+    /// There's no timeout by default.
+    #[cfg(feature = "ureq")]
+    pub fn timeout(&mut self, timeout: Duration) -> &mut TmdbBuilder {
+        self.ureq.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the timeout for connecting to TMDB.
+    ///
+    /// There's no timeout by default.
+    #[cfg(feature = "ureq")]
+    pub fn timeout_connect(
+        &mut self,
+        timeout_connect: Duration,
+    ) -> &mut TmdbBuilder {
+        self.ureq.timeout_connect = Some(timeout_connect);
+
+        self
+    }
+
+    /// Sets the timeout for each read of the response.
+    ///
+    /// There's no timeout by default.
+    #[cfg(feature = "ureq")]
+    pub fn timeout_read(
+        &mut self,
+        timeout_read: Duration,
+    ) -> &mut TmdbBuilder {
+        self.ureq.timeout_read = Some(timeout_read);
+
+        self
+    }
+
+    /// Sets the proxy that requests are sent through.
+    ///
+    /// The proxy has the form `<protocol>://<user>:<password>@<host>:<port>`,
+    /// where everything but the host is optional. The protocol is `http` or
+    /// `socks5`, and defaults to `http`.
+    #[cfg(feature = "ureq")]
+    pub fn proxy<S>(&mut self, proxy: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.ureq.proxy = Some(proxy.into());
+
+        self
+    }
+
+    /// Adds a DER-encoded root certificate to trust, on top of the default
+    /// root certificates.
+    ///
+    /// This is useful behind proxies that intercept TLS.
+    #[cfg(feature = "ureq")]
+    pub fn root_certificate<B>(&mut self, der: B) -> &mut TmdbBuilder
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.ureq.root_certificates.push(der.into());
+
+        self
+    }
+
+    /// Sets the `User-Agent` header of requests.
+    ///
+    /// The default is `eiga/<version>`.
+    #[cfg(feature = "ureq")]
+    pub fn user_agent<S>(&mut self, user_agent: S) -> &mut TmdbBuilder
+    where
+        S: Into<String>,
+    {
+        self.ureq.user_agent = Some(user_agent.into());
+
+        self
+    }
+
+    /// Sets the agent that sends requests.
+    ///
+    /// The agent is used as is, so the timeouts, proxy, root certificates,
+    /// and user agent of this builder don't apply to it.
+    #[cfg(feature = "ureq")]
+    pub fn agent(&mut self, agent: Agent) -> &mut TmdbBuilder {
+        self.ureq.agent = Some(agent);
 
         self
     }
 
+    /// Returns the `User-Agent` header the backend sends, if it's known.
+    ///
+    /// It's only known for the default backend.
+    fn backend_user_agent(&self) -> Option<String> {
+        if self.backend.is_some() {
+            return None;
+        }
+
+        #[cfg(feature = "ureq")]
+        return self.ureq.user_agent().map(str::to_owned);
+
+        #[cfg(not(feature = "ureq"))]
+        None
+    }
+
+    /// Builds the backend that sends requests.
+    fn build_backend(&self) -> Result<Arc<dyn HttpBackend>, Error> {
+        if let Some(backend) = &self.backend {
+            return Ok(Arc::clone(backend));
+        }
+
+        #[cfg(feature = "ureq")]
+        return Ok(Arc::new(self.ureq.build()?));
+
+        #[cfg(not(feature = "ureq"))]
+        Err(Error::Config(
+            "an HTTP backend must be set without the `ureq` feature".into(),
+        ))
+    }
+
     /// Builds a new `Tmdb` based on the current configuration.
     pub fn build(&self) -> Result<Tmdb, Error> {
-        let base_url = Url::parse(self.base_url.unwrap_or(TMDB_BASE_URL))?;
-
-        let auth_header =
-            Header::new("authorization", &format!("Bearer {}", self.token));
+        let base_url =
+            Url::parse(self.base_url.as_deref().unwrap_or(TMDB_BASE_URL))?;
+        let base_url_v4 = match &self.base_url_v4 {
+            Some(base_url_v4) => Url::parse(base_url_v4)?,
+            None => v4_base_url(&base_url),
+        };
 
-        // TODO: Should I set the User-Agent header?
         Ok(Tmdb {
             base_url,
-            auth_header,
-            agent: Agent::new(),
+            base_url_v4,
+            authorization: bearer(&self.token),
+            user_authorization: self.access_token.as_deref().map(bearer),
+            defaults: self.defaults.clone(),
+            user_agent: self.backend_user_agent(),
+            throttle: Arc::new(Throttle::new(
+                self.max_retries,
+                self.rate_limit,
+            )?),
+            backend: self.build_backend()?,
+            session: self.session.clone(),
         })
     }
 }
 
 /// A client for sending requests to the TMDB API.
-#[derive(Debug)]
+///
+/// `Tmdb` is cheap to clone. Clones share the HTTP backend, and so the
+/// connection pool of the default backend, as well as the rate limit, which
+/// makes it easy to use one client from many threads. Setting a session or
+/// access token on a clone doesn't affect the others.
+#[derive(Clone, Debug)]
 pub struct Tmdb {
     base_url: Url,
-    auth_header: Header,
-    agent: Agent,
+    base_url_v4: Url,
+    authorization: String,
+    user_authorization: Option<String>,
+    defaults: Vec<(&'static str, String)>,
+    user_agent: Option<String>,
+    throttle: Arc<Throttle>,
+    backend: Arc<dyn HttpBackend>,
+    session: Option<Session>,
 }
 
 impl Tmdb {
     /// Constructs a new `Tmdb` from the given token.
     ///
     /// Use `Tmdb::builder` if you want to configure the base URL for requests.
+    #[cfg(feature = "ureq")]
     pub fn new<S>(token: S) -> Tmdb
     where
         S: Into<String>,
     {
-        // TmdbBuilder only fails if the base URL is invalid. The default URL
-        // is valid so it's safe to unwrap here.
+        // TmdbBuilder only fails if the base URL, proxy, root certificates,
+        // or rate limit are invalid. The defaults are valid so it's safe to
+        // unwrap here.
         TmdbBuilder::new(token).build().unwrap()
     }
 
+    /// Constructs a new `Tmdb` from `TMDB_*` environment variables.
+    ///
+    /// See `TmdbBuilder::from_env` for the variables.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eiga::Tmdb;
+    ///
+    /// // Reads the token from TMDB_TOKEN.
+    /// let tmdb = Tmdb::from_env().unwrap();
+    /// ```
+    pub fn from_env() -> Result<Tmdb, Error> {
+        TmdbBuilder::from_env()?.build()
+    }
+
     /// Constructs a new `TmdbBuilder` from the given token.
-    pub fn builder<'a, S>(token: S) -> TmdbBuilder<'a>
+    pub fn builder<S>(token: S) -> TmdbBuilder
     where
         S: Into<String>,
     {
         TmdbBuilder::new(token)
     }
 
+    /// Attaches a session ID to requests for endpoints that require a
+    /// session.
+    ///
+    /// This is useful after creating a session with
+    /// [`authentication::CreateSession`]. It replaces any session that was
+    /// attached before.
+    ///
+    /// [`authentication::CreateSession`]: authentication/struct.CreateSession.html
+    pub fn set_session_id<S>(&mut self, session_id: S)
+    where
+        S: Into<String>,
+    {
+        self.session = Some(Session::User(session_id.into()));
+    }
+
+    /// Attaches a guest session ID to requests for endpoints that require a
+    /// session.
+    ///
+    /// This replaces any session that was attached before.
+    pub fn set_guest_session_id<S>(&mut self, guest_session_id: S)
+    where
+        S: Into<String>,
+    {
+        self.session = Some(Session::Guest(guest_session_id.into()));
+    }
+
+    /// Sets the user access token that authorizes v4 requests on behalf of
+    /// a user.
+    ///
+    /// This is useful after creating an access token with
+    /// [`v4::auth::CreateAccessToken`].
+    ///
+    /// [`v4::auth::CreateAccessToken`]: v4/auth/struct.CreateAccessToken.html
+    pub fn set_access_token<S>(&mut self, access_token: S)
+    where
+        S: Into<String>,
+    {
+        self.user_authorization = Some(bearer(&access_token.into()));
+    }
+
+    /// Downloads the image with the given path and size and writes it to
+    /// `writer`. Returns the number of bytes written.
+    ///
+    /// The image is streamed to `writer` as it's received. The download is
+    /// retried and rate limited like other requests, as set with
+    /// `TmdbBuilder::retry` and `TmdbBuilder::rate_limit`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::error::Error;
+    /// use std::fs::File;
+    ///
+    /// use eiga::{ImageUrlBuilder, PosterSize, Tmdb};
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let tmdb = Tmdb::new("<token>");
+    ///     let image_url_builder = ImageUrlBuilder::fetch(&tmdb)?;
+    ///
+    ///     let mut file = File::create("poster.jpg")?;
+    ///     tmdb.download_image(
+    ///         &image_url_builder,
+    ///         "/kqjL17yufvn9OVLyXYpvtyrFfak.jpg",
+    ///         PosterSize::W500,
+    ///         &mut file,
+    ///     )?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn download_image<S, W>(
+        &self,
+        image_url_builder: &ImageUrlBuilder,
+        path: &str,
+        size: S,
+        writer: &mut W,
+    ) -> Result<u64, Error>
+    where
+        S: ImageSize,
+        W: io::Write + ?Sized,
+    {
+        let url = image_url_builder.url(path, size)?;
+
+        // The image CDN doesn't need the token, so don't send it.
+        let response = self.throttle.send(&Method::GET, || {
+            self.backend.send_streaming(&Method::GET, &url, &[], None)
+        })?;
+
+        let code = response.status();
+        if code >= 400 {
+            let message = StatusCode::from_u16(code)
+                .ok()
+                .and_then(|status| status.canonical_reason())
+                .unwrap_or_default();
+
+            return Err(Error::Tmdb {
+                code,
+                message: message.to_owned(),
+            });
+        }
+
+        io::copy(&mut response.into_body(), writer).map_err(Error::Io)
+    }
+
     /// Calls the given endpoint and returns the response.
-    fn call<E>(&self, endpoint: &E) -> Result<Response, Error>
+    fn call<E>(&self, endpoint: &E) -> Result<HttpResponse, Error>
     where
         E: Endpoint,
     {
-        let mut url = self.base_url.join(&endpoint.path())?;
-        endpoint.parameters().append_to_url(&mut url);
+        let response = self.execute(endpoint)?;
 
-        let request = self
-            .agent
-            .request_url(endpoint.method().as_str(), &url)
-            // TODO: Is it always safe to unwrap here?
-            .set(self.auth_header.name(), self.auth_header.value().unwrap());
+        check_response(endpoint, response)
+    }
 
-        let response = if let Some(body) = endpoint.body() {
-            request.send_bytes(&body)
-        } else {
-            request.call()
-        };
+    /// Sends the request for the given endpoint and returns the response,
+    /// whatever its status.
+    pub(crate) fn execute<E>(
+        &self,
+        endpoint: &E,
+    ) -> Result<HttpResponse, Error>
+    where
+        E: Endpoint,
+    {
+        let prepared = self.prepare(endpoint)?;
+
+        let mut headers = vec![("authorization", prepared.authorization())];
+        if let Some(body) = prepared.body() {
+            headers.push(("content-type", body.content_type()));
+        }
+
+        self.throttle.send(prepared.method(), || {
+            self.backend.send(
+                prepared.method(),
+                prepared.url(),
+                &headers,
+                prepared.body().map(|body| body.as_bytes()),
+            )
+        })
+    }
 
-        match response {
-            Ok(response) => Ok(response),
-            Err(Status(422, response)) => {
-                let error: UnprocessableEntityError = response.into_json()?;
+    /// Returns the request this client would send for the given endpoint,
+    /// without sending it.
+    ///
+    /// See [`PreparedRequest`] for an example.
+    pub fn prepare<E>(&self, endpoint: &E) -> Result<PreparedRequest, Error>
+    where
+        E: Endpoint,
+    {
+        endpoint.validate()?;
+
+        let base_url = match endpoint.api_version() {
+            ApiVersion::V3 => &self.base_url,
+            ApiVersion::V4 => &self.base_url_v4,
+        };
+        let mut url = base_url.join(&endpoint.path())?;
 
-                Err(Error::Tmdb {
-                    code: 422,
-                    message: error.errors.join(", "),
-                })
+        let mut authorization = &self.authorization;
+        let mut parameters = endpoint.parameters();
+        for (name, value) in &self.defaults {
+            if endpoint.accepts_default(name)
+                && !parameters.iter().any(|(key, _)| key == *name)
+            {
+                parameters.push(name, Some(value.as_str()));
             }
-            Err(Status(code, response)) => {
-                let error: TmdbError = response.into_json()?;
+        }
+        if endpoint.requires_session() {
+            match (endpoint.api_version(), &self.session) {
+                (ApiVersion::V4, _) => {
+                    if let Some(user_authorization) = &self.user_authorization
+                    {
+                        authorization = user_authorization;
+                    }
+                }
+                (_, Some(Session::User(session_id))) => {
+                    parameters.push("session_id", Some(session_id.as_str()))
+                }
+                (_, Some(Session::Guest(guest_session_id))) => parameters
+                    .push("guest_session_id", Some(guest_session_id.as_str())),
+                (_, None) => {}
+            }
+        }
+        parameters.append_to_url(&mut url);
+
+        Ok(PreparedRequest::new(
+            endpoint.method(),
+            url,
+            authorization.clone(),
+            self.user_agent.clone(),
+            endpoint.body()?,
+        ))
+    }
 
-                Err(Error::Tmdb {
-                    code,
-                    message: error.status_message,
-                })
+    /// Returns the credentials this client sends, each paired with the
+    /// placeholder that replaces it in recorded requests.
+    #[cfg(feature = "testing")]
+    pub(crate) fn credentials(&self) -> Vec<(&str, &'static str)> {
+        let mut credentials = Vec::new();
+        if let Some(token) = self.authorization.strip_prefix("Bearer ") {
+            credentials.push((token, "<token>"));
+        }
+        if let Some(access_token) = self
+            .user_authorization
+            .as_deref()
+            .and_then(|authorization| authorization.strip_prefix("Bearer "))
+        {
+            credentials.push((access_token, "<access_token>"));
+        }
+        match &self.session {
+            Some(Session::User(session_id)) => {
+                credentials.push((session_id, "<session_id>"))
+            }
+            Some(Session::Guest(guest_session_id)) => {
+                credentials.push((guest_session_id, "<guest_session_id>"))
             }
-            Err(Transport(transport)) => Err(Error::Transport(transport)),
+            None => {}
         }
+
+        credentials
+    }
+}
+
+/// Returns the given response if its status is a success. Otherwise, returns
+/// the TMDB error in the response.
+pub(crate) fn check_response<E>(
+    endpoint: &E,
+    response: HttpResponse,
+) -> Result<HttpResponse, Error>
+where
+    E: Endpoint,
+{
+    let code = response.status();
+    if code < 400 {
+        return Ok(response);
+    }
+
+    if code == 422 {
+        let error: UnprocessableEntityError = deserialize(response.body())?;
+
+        return Err(Error::Tmdb {
+            code: 422,
+            message: error.errors.join(", "),
+        });
     }
+
+    let error: TmdbError = deserialize(response.body())?;
+
+    if endpoint.is_success(code, error.status_code) {
+        return Ok(response);
+    }
+
+    Err(Error::Tmdb {
+        code,
+        message: error.status_message,
+    })
+}
+
+/// Deserializes a JSON response body.
+pub(crate) fn deserialize<D>(body: &[u8]) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+{
+    serde_json::from_slice(body)
+        .map_err(|err| Error::Deserialize(io::Error::from(err)))
+}
+
+/// Returns the value of a bearer `Authorization` header for the given token.
+fn bearer(token: &str) -> String {
+    format!("Bearer {}", token)
+}
+
+/// Returns the v4 base URL that corresponds to the given base URL.
+///
+/// The path of the default base URL ends in `/3/`, so this swaps it for
+/// `/4/`. Other base URLs are returned as is.
+fn v4_base_url(base_url: &Url) -> Url {
+    let mut base_url_v4 = base_url.clone();
+
+    let path = base_url.path();
+    if let Some(prefix) =
+        path.strip_suffix("/3/").or_else(|| path.strip_suffix("/3"))
+    {
+        base_url_v4.set_path(&format!("{}/4/", prefix));
+    }
+
+    base_url_v4
 }
 
 impl Client for Tmdb {
@@ -144,7 +800,7 @@ impl Client for Tmdb {
     {
         let response = self.call(endpoint)?;
 
-        response.into_json::<D>().map_err(Error::Deserialize)
+        deserialize(response.body())
     }
 
     fn ignore<E>(&self, endpoint: &E) -> Result<(), Error>
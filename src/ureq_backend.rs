@@ -0,0 +1,194 @@
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::Method;
+use rustls::{Certificate, ClientConfig, OwnedTrustAnchor, RootCertStore};
+use ureq::{
+    Agent, AgentBuilder,
+    Error::{Status, Transport},
+    Proxy,
+};
+use url::Url;
+
+use crate::{Error, HttpBackend, HttpResponse, HttpStream};
+
+/// The default `User-Agent` header.
+const USER_AGENT: &str = concat!("eiga/", env!("CARGO_PKG_VERSION"));
+
+/// An HTTP backend that sends requests with a `ureq` agent.
+///
+/// This is the default backend of [`Tmdb`]. It's only available with the
+/// `ureq` feature.
+///
+/// [`Tmdb`]: crate::Tmdb
+#[derive(Debug)]
+pub struct UreqBackend {
+    agent: Agent,
+}
+
+impl UreqBackend {
+    /// Constructs a new `UreqBackend` that sends requests with `agent`.
+    pub fn new(agent: Agent) -> UreqBackend {
+        UreqBackend { agent }
+    }
+
+    /// Sends a request and returns the response, whatever its status.
+    fn call(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<ureq::Response, Error> {
+        let mut request = self.agent.request_url(method.as_str(), url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+
+        let response = match body {
+            Some(body) => request.send_bytes(body),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) | Err(Status(_, response)) => Ok(response),
+            Err(Transport(transport)) => Err(Error::Transport(transport)),
+        }
+    }
+}
+
+impl Default for UreqBackend {
+    /// Constructs a new `UreqBackend` with a `User-Agent` of
+    /// `eiga/<version>`.
+    fn default() -> UreqBackend {
+        UreqBackend::new(AgentBuilder::new().user_agent(USER_AGENT).build())
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn send(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<HttpResponse, Error> {
+        let response = self.call(method, url, headers, body)?;
+
+        let status = response.status();
+        let headers = response_headers(&response);
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(Error::Io)?;
+
+        Ok(HttpResponse::new(status, headers, body))
+    }
+
+    fn send_streaming(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<HttpStream, Error> {
+        let response = self.call(method, url, headers, body)?;
+
+        Ok(HttpStream::new(
+            response.status(),
+            response_headers(&response),
+            response.into_reader(),
+        ))
+    }
+}
+
+/// Returns the headers of a `ureq` response.
+fn response_headers(response: &ureq::Response) -> Vec<(String, String)> {
+    response
+        .headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_owned();
+            Some((name, value))
+        })
+        .collect()
+}
+
+/// The configuration of the default backend of a `TmdbBuilder`.
+#[derive(Debug, Default)]
+pub(crate) struct UreqConfig {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) timeout_connect: Option<Duration>,
+    pub(crate) timeout_read: Option<Duration>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) root_certificates: Vec<Vec<u8>>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) agent: Option<Agent>,
+}
+
+impl UreqConfig {
+    /// Builds a new `UreqBackend` based on this configuration.
+    pub(crate) fn build(&self) -> Result<UreqBackend, Error> {
+        if let Some(agent) = &self.agent {
+            return Ok(UreqBackend::new(agent.clone()));
+        }
+
+        let mut builder = AgentBuilder::new()
+            .user_agent(self.user_agent.as_deref().unwrap_or(USER_AGENT));
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout_connect) = self.timeout_connect {
+            builder = builder.timeout_connect(timeout_connect);
+        }
+        if let Some(timeout_read) = self.timeout_read {
+            builder = builder.timeout_read(timeout_read);
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::new(proxy).map_err(|err| {
+                Error::Config(format!("invalid proxy: {}", err))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if !self.root_certificates.is_empty() {
+            builder = builder.tls_config(Arc::new(self.tls_config()?));
+        }
+
+        Ok(UreqBackend::new(builder.build()))
+    }
+
+    /// Returns the `User-Agent` header the backend sends, or `None` if it's
+    /// up to a prebuilt agent.
+    pub(crate) fn user_agent(&self) -> Option<&str> {
+        match self.agent {
+            Some(_) => None,
+            None => Some(self.user_agent.as_deref().unwrap_or(USER_AGENT)),
+        }
+    }
+
+    /// Returns a TLS configuration that trusts the default root
+    /// certificates and the added root certificates.
+    fn tls_config(&self) -> Result<ClientConfig, Error> {
+        let mut root_store = RootCertStore::empty();
+        root_store.add_server_trust_anchors(
+            webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|trust_anchor| {
+                OwnedTrustAnchor::from_subject_spki_name_constraints(
+                    trust_anchor.subject,
+                    trust_anchor.spki,
+                    trust_anchor.name_constraints,
+                )
+            }),
+        );
+        for der in &self.root_certificates {
+            root_store.add(&Certificate(der.clone())).map_err(|err| {
+                Error::Config(format!("invalid root certificate: {}", err))
+            })?;
+        }
+
+        Ok(ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(root_store)
+            .with_no_client_auth())
+    }
+}
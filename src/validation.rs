@@ -0,0 +1,46 @@
+use crate::Error;
+
+/// Checks that `value` is a rating TMDB accepts.
+///
+/// Ratings must be between 0.5 and 10.0, inclusive, in steps of 0.5.
+pub(crate) fn rating(value: f32) -> Result<(), Error> {
+    if (0.5..=10.0).contains(&value) && (value * 2.0).fract() == 0.0 {
+        Ok(())
+    } else {
+        Err(Error::Validation(format!(
+            "rating must be between 0.5 and 10.0 in steps of 0.5, got {}",
+            value
+        )))
+    }
+}
+
+/// The maximum number of responses TMDB appends to a response.
+const MAX_APPEND_TO_RESPONSE: usize = 20;
+
+/// Checks that `len` responses can be appended to a response.
+pub(crate) fn append_to_response(len: usize) -> Result<(), Error> {
+    if len <= MAX_APPEND_TO_RESPONSE {
+        Ok(())
+    } else {
+        Err(Error::Validation(format!(
+            "at most {} responses can be appended, got {}",
+            MAX_APPEND_TO_RESPONSE, len
+        )))
+    }
+}
+
+/// The highest page TMDB responds with.
+pub(crate) const MAX_PAGE: u16 = 500;
+
+/// Checks that `page`, if set, is a page TMDB responds with.
+pub(crate) fn page(page: Option<u16>) -> Result<(), Error> {
+    match page {
+        Some(page) if !(1..=MAX_PAGE).contains(&page) => {
+            Err(Error::Validation(format!(
+                "page must be between 1 and {}, got {}",
+                MAX_PAGE, page
+            )))
+        }
+        _ => Ok(()),
+    }
+}
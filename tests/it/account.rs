@@ -0,0 +1,160 @@
+use eiga::{account, Language};
+use ureq::serde_json::json;
+
+use crate::TestClient;
+
+#[test]
+fn get_details() {
+    let account_details_endpoint = account::Details::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_details_endpoint);
+}
+
+#[test]
+fn get_favorite_movies() {
+    let account_favorite_movies_endpoint = account::FavoriteMovies::new(548)
+        .language(Language::En)
+        .page(1)
+        .sort_by(account::SortBy::CreatedAtAsc);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/favorite/movies")
+        .parameters(&[
+            ("session_id", "<session_id>"),
+            ("language", "en"),
+            ("page", "1"),
+            ("sort_by", "created_at.asc"),
+        ])
+        .session_id("<session_id>")
+        .check(account_favorite_movies_endpoint);
+}
+
+#[test]
+fn get_favorite_tv() {
+    let account_favorite_tv_endpoint =
+        account::FavoriteTv::new(548).sort_by(account::SortBy::CreatedAtDesc);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/favorite/tv")
+        .parameters(&[
+            ("session_id", "<session_id>"),
+            ("sort_by", "created_at.desc"),
+        ])
+        .session_id("<session_id>")
+        .check(account_favorite_tv_endpoint);
+}
+
+#[test]
+fn get_watchlist_movies() {
+    let account_watchlist_movies_endpoint = account::WatchlistMovies::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/watchlist/movies")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_watchlist_movies_endpoint);
+}
+
+#[test]
+fn get_watchlist_tv() {
+    let account_watchlist_tv_endpoint = account::WatchlistTv::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/watchlist/tv")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_watchlist_tv_endpoint);
+}
+
+#[test]
+fn get_rated_movies() {
+    let account_rated_movies_endpoint = account::RatedMovies::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/rated/movies")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_rated_movies_endpoint);
+}
+
+#[test]
+fn get_rated_tv() {
+    let account_rated_tv_endpoint = account::RatedTv::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/rated/tv")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_rated_tv_endpoint);
+}
+
+#[test]
+fn get_rated_episodes() {
+    let account_rated_episodes_endpoint = account::RatedEpisodes::new(548);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/rated/tv/episodes")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(account_rated_episodes_endpoint);
+}
+
+#[test]
+fn get_lists() {
+    let account_lists_endpoint = account::Lists::new(548).page(2);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/548/lists")
+        .parameters(&[("session_id", "<session_id>"), ("page", "2")])
+        .session_id("<session_id>")
+        .check(account_lists_endpoint);
+}
+
+#[test]
+fn add_favorite() {
+    let account_add_favorite_endpoint =
+        account::AddFavorite::new(548, account::MediaType::Movie, 550, true);
+
+    TestClient::new()
+        .method("POST")
+        .path("account/548/favorite")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({
+            "media_type": "movie",
+            "media_id": 550,
+            "favorite": true,
+        }))
+        .session_id("<session_id>")
+        .check(account_add_favorite_endpoint);
+}
+
+#[test]
+fn add_to_watchlist() {
+    let account_add_to_watchlist_endpoint =
+        account::AddToWatchlist::new(548, account::MediaType::Tv, 1399, false);
+
+    TestClient::new()
+        .method("POST")
+        .path("account/548/watchlist")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({
+            "media_type": "tv",
+            "media_id": 1399,
+            "watchlist": false,
+        }))
+        .session_id("<session_id>")
+        .check(account_add_to_watchlist_endpoint);
+}
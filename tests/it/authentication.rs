@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use eiga::{authentication, Endpoint};
+use http::Method;
+use ureq::serde_json::json;
+
+use crate::TestClient;
+
+/// An endpoint that requires a session, for checking that the client attaches
+/// its session to requests.
+struct SessionEndpoint;
+
+impl Endpoint for SessionEndpoint {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        "account".into()
+    }
+
+    fn requires_session(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn create_request_token() {
+    let create_request_token_endpoint =
+        authentication::CreateRequestToken::new();
+
+    TestClient::new()
+        .method("GET")
+        .path("authentication/token/new")
+        .check(create_request_token_endpoint);
+}
+
+#[test]
+fn create_session() {
+    let create_session_endpoint =
+        authentication::CreateSession::new("<request_token>");
+
+    TestClient::new()
+        .method("POST")
+        .path("authentication/session/new")
+        .body(json!({ "request_token": "<request_token>" }))
+        .check(create_session_endpoint);
+}
+
+#[test]
+fn create_session_with_login() {
+    let create_session_with_login_endpoint =
+        authentication::CreateSessionWithLogin::new(
+            "<username>",
+            "<password>",
+            "<request_token>",
+        );
+
+    TestClient::new()
+        .method("POST")
+        .path("authentication/token/validate_with_login")
+        .body(json!({
+            "username": "<username>",
+            "password": "<password>",
+            "request_token": "<request_token>",
+        }))
+        .check(create_session_with_login_endpoint);
+}
+
+#[test]
+fn create_guest_session() {
+    let create_guest_session_endpoint =
+        authentication::CreateGuestSession::new();
+
+    TestClient::new()
+        .method("GET")
+        .path("authentication/guest_session/new")
+        .check(create_guest_session_endpoint);
+}
+
+#[test]
+fn delete_session() {
+    let delete_session_endpoint =
+        authentication::DeleteSession::new("<session_id>");
+
+    TestClient::new()
+        .method("DELETE")
+        .path("authentication/session")
+        .body(json!({ "session_id": "<session_id>" }))
+        .check(delete_session_endpoint);
+}
+
+#[test]
+fn validate() {
+    let validate_endpoint = authentication::Validate::new();
+
+    TestClient::new()
+        .method("GET")
+        .path("authentication")
+        .check(validate_endpoint);
+}
+
+#[test]
+fn attach_session_id() {
+    TestClient::new()
+        .method("GET")
+        .path("account")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(SessionEndpoint);
+}
+
+#[test]
+fn attach_guest_session_id() {
+    TestClient::new()
+        .method("GET")
+        .path("account")
+        .parameters(&[("guest_session_id", "<guest_session_id>")])
+        .guest_session_id("<guest_session_id>")
+        .check(SessionEndpoint);
+}
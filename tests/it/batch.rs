@@ -0,0 +1,79 @@
+use eiga::{movie, Client, Error, Tmdb};
+use httpmock::prelude::*;
+use ureq::serde_json::{json, Value};
+
+/// Mocks movie details for the given IDs on `server`. Other IDs aren't
+/// found.
+fn mock_details(server: &MockServer, ids: &[u64]) {
+    for id in ids {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/movie/{}", id));
+            then.status(200).json_body(json!({ "id": id }));
+        });
+    }
+    server.mock(|when, then| {
+        when.method(GET);
+        then.status(404).json_body(json!({
+            "status_code": 34,
+            "status_message": "The resource you requested could not be found.",
+        }));
+    });
+}
+
+#[test]
+fn send_many_in_order() {
+    let server = MockServer::start();
+    mock_details(&server, &[1, 2, 4, 5]);
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let endpoints = (1..=5).map(movie::Details::new);
+    let results: Vec<Result<Value, Error>> =
+        tmdb.send_many(endpoints).concurrency(3).in_order();
+
+    assert_eq!(5, results.len());
+    for (id, result) in (1..=5).zip(results) {
+        if id == 3 {
+            assert!(matches!(result, Err(Error::Tmdb { code: 404, .. })));
+        } else {
+            assert_eq!(json!({ "id": id }), result.unwrap());
+        }
+    }
+}
+
+#[test]
+fn send_many_completed() {
+    let server = MockServer::start();
+    mock_details(&server, &[1, 2, 3]);
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let endpoints = [1, 2, 3].map(movie::Details::new);
+    let mut indices = tmdb.send_many(endpoints).completed(|completed| {
+        completed
+            .map(|(index, result): (usize, Result<Value, _>)| {
+                assert!(result.is_ok());
+                index
+            })
+            .collect::<Vec<_>>()
+    });
+    indices.sort_unstable();
+
+    assert_eq!(vec![0, 1, 2], indices);
+}
+
+#[test]
+fn send_many_empty() {
+    let tmdb = Tmdb::new("<token>");
+
+    let results: Vec<Result<Value, Error>> = tmdb
+        .send_many(Vec::<movie::Details>::new())
+        .concurrency(0)
+        .in_order();
+
+    assert!(results.is_empty());
+}
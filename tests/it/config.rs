@@ -0,0 +1,230 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use eiga::{movie, search, Client, Error, Tmdb, TmdbBuilder};
+use httpmock::prelude::*;
+
+/// Serializes tests that change the environment.
+static ENV: Mutex<()> = Mutex::new(());
+
+const VARS: [&str; 16] = [
+    "TMDB_TOKEN",
+    "TMDB_BASE_URL",
+    "TMDB_BASE_URL_V4",
+    "TMDB_SESSION_ID",
+    "TMDB_ACCESS_TOKEN",
+    "TMDB_TIMEOUT",
+    "TMDB_TIMEOUT_CONNECT",
+    "TMDB_TIMEOUT_READ",
+    "TMDB_PROXY",
+    "TMDB_USER_AGENT",
+    "TMDB_LANGUAGE",
+    "TMDB_REGION",
+    "TMDB_INCLUDE_ADULT",
+    "TMDB_WATCH_REGION",
+    "TMDB_RETRY",
+    "TMDB_RATE_LIMIT",
+];
+
+/// Locks the environment and clears the `TMDB_*` variables.
+fn lock_env() -> MutexGuard<'static, ()> {
+    let guard = ENV.lock().unwrap_or_else(|err| err.into_inner());
+    for var in VARS {
+        env::remove_var(var);
+    }
+
+    guard
+}
+
+/// Writes `contents` to a config file that's unique to the test.
+fn config_file(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!(
+        "eiga-config-{}-{}.toml",
+        name,
+        std::process::id()
+    ));
+    fs::write(&path, contents).unwrap();
+
+    path
+}
+
+#[test]
+fn from_env() {
+    let _env = lock_env();
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.path("/movie/45706")
+            .header("authorization", "Bearer <env token>")
+            .header("user-agent", "env/1.0");
+        then.status(200);
+    });
+    env::set_var("TMDB_TOKEN", "<env token>");
+    env::set_var("TMDB_BASE_URL", server.base_url());
+    env::set_var("TMDB_USER_AGENT", "env/1.0");
+    env::set_var("TMDB_TIMEOUT", "2.5");
+
+    let tmdb = Tmdb::from_env().unwrap();
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn from_env_missing_token() {
+    let _env = lock_env();
+
+    let result = Tmdb::from_env();
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message)) if message.contains("TMDB_TOKEN")
+    ));
+}
+
+#[test]
+fn from_env_malformed_timeout() {
+    let _env = lock_env();
+    env::set_var("TMDB_TOKEN", "<token>");
+    env::set_var("TMDB_TIMEOUT_READ", "soon");
+
+    let result = TmdbBuilder::from_env();
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message))
+            if message
+                == r#"TMDB_TIMEOUT_READ must be a number of seconds, got "soon""#
+    ));
+}
+
+#[test]
+fn from_env_malformed_retry() {
+    let _env = lock_env();
+    env::set_var("TMDB_TOKEN", "<token>");
+    env::set_var("TMDB_RETRY", "-1");
+
+    let result = TmdbBuilder::from_env();
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message))
+            if message == r#"TMDB_RETRY must be a non-negative integer, got "-1""#
+    ));
+}
+
+#[test]
+fn from_env_zero_rate_limit() {
+    let _env = lock_env();
+    env::set_var("TMDB_TOKEN", "<token>");
+    env::set_var("TMDB_RATE_LIMIT", "0");
+
+    let result = Tmdb::from_env();
+
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[test]
+fn from_config() {
+    let _env = lock_env();
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.path("/movie/45706")
+            .header("authorization", "Bearer <env token>")
+            .header("user-agent", "file/1.0");
+        then.status(200);
+    });
+    let path = config_file(
+        "from_config",
+        &format!(
+            "token = \"<file token>\"\nbase_url = \"{}\"\n\
+             user_agent = \"file/1.0\"\ntimeout = 30\n\
+             retry = 2\nrate_limit = 40\n",
+            server.base_url()
+        ),
+    );
+    // Environment variables take precedence over the file.
+    env::set_var("TMDB_TOKEN", "<env token>");
+
+    let tmdb = TmdbBuilder::from_config(&path).unwrap().build().unwrap();
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_config_unknown_setting() {
+    let _env = lock_env();
+    let path = config_file("unknown", "token = \"<token>\"\ntimeuot = 30\n");
+
+    let result = TmdbBuilder::from_config(&path);
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message)) if message.contains("timeuot")
+    ));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_config_negative_timeout() {
+    let _env = lock_env();
+    let path = config_file("negative", "token = \"<token>\"\ntimeout = -1\n");
+
+    let result = TmdbBuilder::from_config(&path);
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message)) if message.starts_with("timeout must be")
+    ));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_config_missing_file() {
+    let _env = lock_env();
+
+    let result = TmdbBuilder::from_config("does/not/exist.toml");
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message)) if message.contains("does/not/exist.toml")
+    ));
+}
+
+#[test]
+fn from_config_defaults() {
+    let _env = lock_env();
+    let path = config_file(
+        "defaults",
+        "token = \"<token>\"\nlanguage = \"ja\"\ninclude_adult = false\n",
+    );
+    env::set_var("TMDB_REGION", "jp");
+
+    let tmdb = TmdbBuilder::from_config(&path).unwrap().build().unwrap();
+    let request = tmdb.prepare(&search::Movies::new("Tampopo")).unwrap();
+
+    assert_eq!(
+        "https://api.themoviedb.org/3/search/movie?query=Tampopo&language=ja\
+         &region=JP&include_adult=false",
+        request.url().as_str()
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_env_unknown_language() {
+    let _env = lock_env();
+    env::set_var("TMDB_TOKEN", "<token>");
+    env::set_var("TMDB_LANGUAGE", "klingon");
+
+    let result = TmdbBuilder::from_env();
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(message))
+            if message == "unknown language: klingon"
+    ));
+}
@@ -0,0 +1,14 @@
+use eiga::credit;
+
+use crate::TestClient;
+
+#[test]
+fn get_details() {
+    let credit_details_endpoint =
+        credit::Details::new("52fe4311c3a36847f8037ee9");
+
+    TestClient::new()
+        .method("GET")
+        .path("credit/52fe4311c3a36847f8037ee9")
+        .check(credit_details_endpoint);
+}
@@ -1,20 +1,22 @@
-use eiga::{movie, search};
+use std::collections::BTreeMap;
+use std::io;
+
+use eiga::{movie, search, Body, Client, Error, Tmdb};
 use ureq::serde_json::json;
 
 use crate::TestClient;
 
 #[test]
 fn handle_unprocessable_entity() {
-    let search_movies_endpoint =
-        search::Movies::new("Cruel Gun Story").page(600);
+    let search_movies_endpoint = search::Movies::new("");
 
     let expected_code = 422;
-    let expected_message = "page must be less than or equal to 500";
+    let expected_message = "query must be provided";
 
     TestClient::new()
         .method("GET")
         .path("search/movie")
-        .parameters(&[("query", "Cruel Gun Story"), ("page", "600")])
+        .parameters(&[("query", "")])
         .status(expected_code)
         .response(json!({ "errors": [expected_message] }))
         .check_err(search_movies_endpoint, expected_code, expected_message);
@@ -34,3 +36,50 @@ fn handle_not_found() {
         .response(json!({"success":false, "status_code":34, "status_message": expected_message}))
         .check_err(movie_details_endpoint, expected_code, expected_message);
 }
+
+#[test]
+fn validate_page() {
+    let tmdb = Tmdb::new("<token>");
+
+    for page in [0, 501] {
+        let endpoint = search::Movies::new("Cruel Gun Story").page(page);
+        let result = tmdb.ignore(&endpoint);
+
+        assert!(
+            matches!(result, Err(Error::Validation(_))),
+            "expected result to be `Err(Error::Validation(_))`, got:\n{:#?}",
+            result
+        );
+    }
+}
+
+#[test]
+fn display_serialize() {
+    // JSON object keys must be strings, so this fails to serialize.
+    let value = BTreeMap::from([(vec![1u8], 1u8)]);
+
+    let err = Body::json(&value).unwrap_err();
+
+    assert!(matches!(err, Error::Serialize(_)));
+    assert_eq!(
+        "failed to serialize the request body: key must be a string",
+        err.to_string()
+    );
+}
+
+#[test]
+fn display_deserialize() {
+    let err = Error::from(io::Error::new(io::ErrorKind::InvalidData, "bad"));
+
+    assert_eq!(
+        "failed to deserialize the TMDB response: bad",
+        err.to_string()
+    );
+}
+
+#[test]
+fn display_url() {
+    let err = Error::from(url::ParseError::EmptyHost);
+
+    assert_eq!("failed to parse a URL: empty host", err.to_string());
+}
@@ -0,0 +1,190 @@
+use std::{env, fs, process};
+
+use eiga::{
+    BackdropSize, Error, ImageStore, ImageUrlBuilder, LogoSize, PosterSize,
+    ProfileSize, StillSize, Tmdb,
+};
+use httpmock::prelude::*;
+use ureq::serde_json::{self, json, Value};
+
+use crate::TestClient;
+
+fn api_configuration() -> Value {
+    json!({
+        "images": {
+            "base_url": "http://image.tmdb.org/t/p/",
+            "secure_base_url": "https://image.tmdb.org/t/p/",
+            "backdrop_sizes": ["w300", "w780", "w1280", "original"],
+            "logo_sizes": ["w45", "w92", "w154", "w185", "w300", "w500", "original"],
+            "poster_sizes": ["w92", "w154", "w185", "w342", "w500", "w780", "original"],
+            "profile_sizes": ["w45", "w185", "h632", "original"],
+            "still_sizes": ["w92", "w185", "w300", "original"]
+        },
+        "change_keys": ["adult", "air_date"]
+    })
+}
+
+#[test]
+fn fetch_image_url_builder() {
+    let image_url_builder = ImageUrlBuilder::fetch(
+        &TestClient::new()
+            .method("GET")
+            .path("configuration")
+            .response(api_configuration()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        "https://image.tmdb.org/t/p/",
+        image_url_builder.base_url().as_str()
+    );
+}
+
+#[test]
+fn build_image_urls() {
+    let image_url_builder: ImageUrlBuilder =
+        serde_json::from_value(api_configuration()).unwrap();
+
+    let urls = [
+        image_url_builder.poster("/poster.jpg", PosterSize::W500),
+        image_url_builder.backdrop("/backdrop.jpg", BackdropSize::Original),
+        image_url_builder.logo("/logo.png", LogoSize::W45),
+        image_url_builder.profile("/profile.jpg", ProfileSize::H632),
+        image_url_builder.still("/still.jpg", StillSize::W300),
+    ];
+    let expected_urls = [
+        "https://image.tmdb.org/t/p/w500/poster.jpg",
+        "https://image.tmdb.org/t/p/original/backdrop.jpg",
+        "https://image.tmdb.org/t/p/w45/logo.png",
+        "https://image.tmdb.org/t/p/h632/profile.jpg",
+        "https://image.tmdb.org/t/p/w300/still.jpg",
+    ];
+
+    for (url, expected_url) in urls.into_iter().zip(expected_urls) {
+        assert_eq!(expected_url, url.unwrap().as_str());
+    }
+}
+
+#[test]
+fn reject_unavailable_size() {
+    let mut api_configuration = api_configuration();
+    api_configuration["images"]["poster_sizes"] = json!(["w92", "original"]);
+    let image_url_builder: ImageUrlBuilder =
+        serde_json::from_value(api_configuration).unwrap();
+
+    let result = image_url_builder.poster("/poster.jpg", PosterSize::W500);
+
+    assert!(
+        matches!(result, Err(Error::Validation(_))),
+        "expected result to be `Err(Error::Validation(_))`, got:\n{:#?}",
+        result
+    );
+}
+
+/// Returns an `ImageUrlBuilder` whose images are served by `server`.
+fn mock_image_url_builder(server: &MockServer) -> ImageUrlBuilder {
+    let mut api_configuration = api_configuration();
+    api_configuration["images"]["secure_base_url"] =
+        json!(server.url("/t/p/"));
+
+    serde_json::from_value(api_configuration).unwrap()
+}
+
+#[test]
+fn download_image() {
+    let server = MockServer::start();
+    let tmdb = Tmdb::new("<token>");
+    let image_url_builder = mock_image_url_builder(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/t/p/w500/poster.jpg");
+        then.status(200).body("<poster>");
+    });
+
+    let mut image = Vec::new();
+    let bytes_written = tmdb
+        .download_image(
+            &image_url_builder,
+            "/poster.jpg",
+            PosterSize::W500,
+            &mut image,
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(8, bytes_written);
+    assert_eq!(b"<poster>", image.as_slice());
+}
+
+#[test]
+fn skip_stored_image() {
+    let server = MockServer::start();
+    let tmdb = Tmdb::new("<token>");
+    let image_url_builder = mock_image_url_builder(&server);
+    let dir = env::temp_dir().join(format!("eiga-it-{}", process::id()));
+    let image_store = ImageStore::new(&dir);
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/t/p/original/backdrop.jpg");
+        then.status(200).body("<backdrop>");
+    });
+
+    assert_eq!(
+        None,
+        image_store
+            .path("/backdrop.jpg", BackdropSize::Original)
+            .unwrap()
+    );
+    for _ in 0..2 {
+        let path = image_store
+            .download(
+                &tmdb,
+                &image_url_builder,
+                "/backdrop.jpg",
+                BackdropSize::Original,
+            )
+            .unwrap();
+
+        // The image is stored by the SHA-256 hash of its content.
+        assert_eq!(
+            dir.join(
+                "1ac069001a0c57b5d5b7ce9653f1ae689886790483b05d2f64b91e5063f85fd9.jpg"
+            ),
+            path
+        );
+        assert_eq!("<backdrop>", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            Some(path),
+            image_store
+                .path("/backdrop.jpg", BackdropSize::Original)
+                .unwrap()
+        );
+    }
+
+    mock.assert_hits(1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn store_identical_images_once() {
+    let server = MockServer::start();
+    let tmdb = Tmdb::new("<token>");
+    let image_url_builder = mock_image_url_builder(&server);
+    let dir = env::temp_dir().join(format!("eiga-it-dedup-{}", process::id()));
+    let image_store = ImageStore::new(&dir);
+
+    server.mock(|when, then| {
+        when.method(GET).path_contains("/t/p/w500/");
+        then.status(200).body("<poster>");
+    });
+
+    let first = image_store
+        .download(&tmdb, &image_url_builder, "/first.jpg", PosterSize::W500)
+        .unwrap();
+    let second = image_store
+        .download(&tmdb, &image_url_builder, "/second.jpg", PosterSize::W500)
+        .unwrap();
+
+    assert_eq!(first, second);
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,156 @@
+use eiga::{list, Client, Language, Page, Pageable};
+use ureq::serde_json::{json, Value};
+
+use crate::TestClient;
+
+#[test]
+fn get_details() {
+    let list_details_endpoint =
+        list::Details::new(8224).language(Language::En).page(1);
+
+    TestClient::new()
+        .method("GET")
+        .path("list/8224")
+        .parameters(&[("language", "en"), ("page", "1")])
+        .check(list_details_endpoint);
+}
+
+#[test]
+fn deserialize_details_page() {
+    let list_details_endpoint = list::Details::new(8224);
+
+    let response: Value = TestClient::new()
+        .method("GET")
+        .path("list/8224")
+        .response(json!({
+            "id": 8224,
+            "items": [{ "id": 11645 }],
+            "page": 1,
+            "total_pages": 1,
+            "total_results": 1,
+        }))
+        .send(&list_details_endpoint)
+        .unwrap();
+    // List details call their results "items", which the envelope maps.
+    let page: Page<Value> =
+        list_details_endpoint.envelope().page(response).unwrap();
+
+    assert_eq!(page.results, vec![json!({ "id": 11645 })]);
+}
+
+#[test]
+fn create() {
+    let create_list_endpoint = list::Create::new("Kurosawa")
+        .description("Films by Akira Kurosawa")
+        .language(Language::En);
+
+    TestClient::new()
+        .method("POST")
+        .path("list")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({
+            "name": "Kurosawa",
+            "description": "Films by Akira Kurosawa",
+            "language": "en",
+        }))
+        .session_id("<session_id>")
+        .status(201)
+        .response(json!({
+            "status_code": 1,
+            "status_message": "The item/record was created successfully.",
+            "success": true,
+            "list_id": 8224,
+        }))
+        .check(create_list_endpoint);
+}
+
+#[test]
+fn add_movie() {
+    let add_movie_endpoint = list::AddMovie::new(8224, 11645);
+
+    TestClient::new()
+        .method("POST")
+        .path("list/8224/add_item")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({ "media_id": 11645 }))
+        .session_id("<session_id>")
+        .status(201)
+        .response(json!({
+            "status_code": 12,
+            "status_message": "The item/record was updated successfully.",
+        }))
+        .check(add_movie_endpoint);
+}
+
+#[test]
+fn remove_movie() {
+    let remove_movie_endpoint = list::RemoveMovie::new(8224, 11645);
+
+    TestClient::new()
+        .method("POST")
+        .path("list/8224/remove_item")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({ "media_id": 11645 }))
+        .session_id("<session_id>")
+        .check(remove_movie_endpoint);
+}
+
+#[test]
+fn clear() {
+    let clear_endpoint = list::Clear::new(8224);
+
+    TestClient::new()
+        .method("POST")
+        .path("list/8224/clear")
+        .parameters(&[("session_id", "<session_id>"), ("confirm", "true")])
+        .session_id("<session_id>")
+        .check(clear_endpoint);
+}
+
+#[test]
+fn delete() {
+    let delete_endpoint = list::Delete::new(8224);
+
+    // TMDB deletes the list but responds with an internal error.
+    TestClient::new()
+        .method("DELETE")
+        .path("list/8224")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .status(500)
+        .response(json!({
+            "status_code": 11,
+            "status_message": "Internal error: Something went wrong, contact TMDb.",
+        }))
+        .check(delete_endpoint);
+}
+
+#[test]
+fn handle_delete_error() {
+    let delete_endpoint = list::Delete::new(8224);
+
+    let expected_code = 401;
+    let expected_message =
+        "Authentication failed: You do not have permissions to access the service.";
+
+    TestClient::new()
+        .method("DELETE")
+        .path("list/8224")
+        .status(expected_code)
+        .response(json!({
+            "status_code": 3,
+            "status_message": expected_message,
+        }))
+        .check_err(delete_endpoint, expected_code, expected_message);
+}
+
+#[test]
+fn get_item_status() {
+    let item_status_endpoint = list::ItemStatus::new(8224, 11645);
+
+    TestClient::new()
+        .method("GET")
+        .path("list/8224/item_status")
+        .parameters(&[("movie_id", "11645")])
+        .check(item_status_endpoint);
+}
@@ -1,7 +1,23 @@
+mod account;
+mod authentication;
+mod batch;
+mod config;
 mod configuration;
+mod credit;
 mod error;
+mod image;
+mod list;
 mod movie;
+mod page;
+mod prepare;
+mod review;
 mod search;
+mod testing;
+mod throttle;
+mod tmdb;
+mod transport;
+mod tv;
+mod v4;
 
 use eiga::{Client, Endpoint, Error, PageIter, Pageable, Tmdb};
 use httpmock::prelude::*;
@@ -12,9 +28,11 @@ use ureq::serde_json::Value;
 struct TestClient<'a> {
     server: MockServer,
     tmdb: Tmdb,
+    access_token: Option<&'a str>,
     method: Option<&'a str>,
     path: Option<&'a str>,
     parameters: Option<&'a [(&'a str, &'a str)]>,
+    body: Option<Value>,
     status: Option<u16>,
     response: Option<Value>,
 }
@@ -23,16 +41,18 @@ impl<'a> TestClient<'a> {
     fn new() -> TestClient<'a> {
         let server = MockServer::start();
         let tmdb = Tmdb::builder("<token>")
-            .base_url(&server.base_url())
+            .base_url(server.base_url())
             .build()
             .unwrap();
 
         TestClient {
             server,
             tmdb,
+            access_token: None,
             method: None,
             path: None,
             parameters: None,
+            body: None,
             status: None,
             response: None,
         }
@@ -59,6 +79,31 @@ impl<'a> TestClient<'a> {
         self
     }
 
+    fn body(mut self, body: Value) -> TestClient<'a> {
+        self.body = Some(body);
+
+        self
+    }
+
+    fn session_id(mut self, session_id: &str) -> TestClient<'a> {
+        self.tmdb.set_session_id(session_id);
+
+        self
+    }
+
+    fn guest_session_id(mut self, guest_session_id: &str) -> TestClient<'a> {
+        self.tmdb.set_guest_session_id(guest_session_id);
+
+        self
+    }
+
+    fn access_token(mut self, access_token: &'a str) -> TestClient<'a> {
+        self.tmdb.set_access_token(access_token);
+        self.access_token = Some(access_token);
+
+        self
+    }
+
     fn status(mut self, status: u16) -> TestClient<'a> {
         self.status = Some(status);
 
@@ -71,9 +116,10 @@ impl<'a> TestClient<'a> {
         self
     }
 
-    fn mock(&self) -> Mock {
+    fn mock(&self) -> Mock<'_> {
         self.server.mock(|mut when, mut then| {
-            when = when.header("authorization", "Bearer <token>");
+            let token = self.access_token.unwrap_or("<token>");
+            when = when.header("authorization", format!("Bearer {}", token));
             if let Some(method) = self.method {
                 when = when.method(method);
             }
@@ -85,6 +131,10 @@ impl<'a> TestClient<'a> {
                     when = when.query_param(*parameter, *value);
                 }
             }
+            if let Some(body) = self.body.clone() {
+                when.header("content-type", "application/json;charset=utf-8")
+                    .json_body(body);
+            }
 
             // Default to 200 since most tests expect it.
             then = then.status(self.status.unwrap_or(200));
@@ -1,4 +1,5 @@
-use eiga::{movie, Country, Language};
+use eiga::{movie, Client, Country, Error, Language, Tmdb};
+use ureq::serde_json::json;
 
 use crate::TestClient;
 
@@ -14,6 +15,53 @@ fn get_details() {
         .check(movie_details_endpoint);
 }
 
+#[test]
+fn get_details_with_appended_responses() {
+    let movie_details_endpoint =
+        movie::Details::new(500).append_to_response(vec![
+            movie::Append::Credits,
+            movie::Append::Videos,
+        ]);
+
+    TestClient::new()
+        .method("GET")
+        .path("movie/500")
+        .parameters(&[("append_to_response", "credits,videos")])
+        .check(movie_details_endpoint);
+}
+
+#[test]
+fn get_details_with_appended_account_states() {
+    let movie_details_endpoint = movie::Details::new(500)
+        .append_to_response(vec![movie::Append::AccountStates]);
+
+    TestClient::new()
+        .method("GET")
+        .path("movie/500")
+        .parameters(&[
+            ("append_to_response", "account_states"),
+            ("session_id", "<session_id>"),
+        ])
+        .session_id("<session_id>")
+        .check(movie_details_endpoint);
+}
+
+#[test]
+fn reject_too_many_appended_responses() {
+    // The request fails before it's sent, so there's no need for a server.
+    let tmdb = Tmdb::new("<token>");
+    let movie_details_endpoint = movie::Details::new(500)
+        .append_to_response(vec![movie::Append::Credits; 21]);
+
+    let result = tmdb.ignore(&movie_details_endpoint);
+
+    assert!(
+        matches!(result, Err(Error::Validation(_))),
+        "expected result to be `Err(Error::Validation(_))`, got:\n{:#?}",
+        result
+    );
+}
+
 #[test]
 fn get_alternative_titles() {
     let movie_alternative_titles_endpoint =
@@ -37,3 +85,56 @@ fn get_credits() {
         .parameters(&[("language", "en")])
         .check(movie_credits_endpoint);
 }
+
+#[test]
+fn get_account_states() {
+    let movie_account_states_endpoint = movie::AccountStates::new(500);
+
+    TestClient::new()
+        .method("GET")
+        .path("movie/500/account_states")
+        .parameters(&[("guest_session_id", "<guest_session_id>")])
+        .guest_session_id("<guest_session_id>")
+        .check(movie_account_states_endpoint);
+}
+
+#[test]
+fn add_rating() {
+    let movie_add_rating_endpoint = movie::AddRating::new(500, 8.5);
+
+    TestClient::new()
+        .method("POST")
+        .path("movie/500/rating")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({ "value": 8.5 }))
+        .session_id("<session_id>")
+        .check(movie_add_rating_endpoint);
+}
+
+#[test]
+fn reject_invalid_rating() {
+    // The request fails before it's sent, so there's no need for a server.
+    let tmdb = Tmdb::new("<token>");
+
+    for value in [0.0, 0.3, 8.25, 10.5, f32::NAN] {
+        let result = tmdb.ignore(&movie::AddRating::new(500, value));
+
+        assert!(
+            matches!(result, Err(Error::Validation(_))),
+            "expected result to be `Err(Error::Validation(_))`, got:\n{:#?}",
+            result
+        );
+    }
+}
+
+#[test]
+fn delete_rating() {
+    let movie_delete_rating_endpoint = movie::DeleteRating::new(500);
+
+    TestClient::new()
+        .method("DELETE")
+        .path("movie/500/rating")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(movie_delete_rating_endpoint);
+}
@@ -0,0 +1,295 @@
+use std::thread;
+
+use eiga::{
+    list, search, Client, Cursor, Endpoint, OwnedPageIter, Page, PageIter,
+    Tmdb,
+};
+use httpmock::prelude::*;
+use httpmock::Mock;
+use ureq::serde_json::{self, json};
+
+/// Returns a `Tmdb` that sends requests to `server`.
+fn tmdb(server: &MockServer) -> Tmdb {
+    Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap()
+}
+
+/// Mocks `total_pages` pages of search results with two results per page.
+///
+/// The results of page `n` are `10 * n` and `10 * n + 1`.
+fn mock_pages(server: &MockServer, total_pages: u16) -> Vec<Mock<'_>> {
+    (1..=total_pages)
+        .map(|page| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/movie")
+                    .query_param("page", page.to_string());
+                then.status(200).json_body(json!({
+                    "page": page,
+                    "results": [page * 10, page * 10 + 1],
+                    "total_results": total_pages * 2,
+                    "total_pages": total_pages,
+                }));
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn page_concurrent() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    let mocks = mock_pages(&server, 5);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let results: Vec<u16> = tmdb
+        .page(&endpoint)
+        .page_concurrent(3)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(vec![10, 11, 20, 21, 30, 31, 40, 41, 50, 51], results);
+    for mock in mocks {
+        mock.assert_hits(1);
+    }
+}
+
+#[test]
+fn page_concurrent_error() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+
+    for page in [1, 2] {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/search/movie")
+                .query_param("page", page.to_string());
+            then.status(200).json_body(json!({
+                "page": page,
+                "results": [page],
+                "total_results": 3,
+                "total_pages": 3,
+            }));
+        });
+    }
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/search/movie")
+            .query_param("page", "3");
+        then.status(404).json_body(json!({
+            "status_code": 34,
+            "status_message": "The resource you requested could not be found.",
+        }));
+    });
+
+    let endpoint = search::Movies::new("Godzilla");
+    let mut page_iter = tmdb.page(&endpoint).page_concurrent(3);
+
+    // The results of the pages before the failed page are still yielded.
+    let result: u16 = page_iter.next().unwrap().unwrap();
+    assert_eq!(1, result);
+    let result: u16 = page_iter.next().unwrap().unwrap();
+    assert_eq!(2, result);
+    assert!(page_iter.next().unwrap().is_err());
+}
+
+#[test]
+fn pages() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    mock_pages(&server, 3);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let mut pages = tmdb.page::<_, u16>(&endpoint).pages();
+
+    assert_eq!(None, pages.total_results());
+    assert_eq!(None, pages.total_pages());
+
+    let first_page = pages.next().unwrap().unwrap();
+    assert_eq!(1, first_page.page);
+    assert_eq!(vec![10, 11], first_page.results);
+    assert_eq!(Some(6), pages.total_results());
+    assert_eq!(Some(3), pages.total_pages());
+
+    let page_numbers: Vec<u16> =
+        pages.map(|page| page.unwrap().page).collect();
+    assert_eq!(vec![2, 3], page_numbers);
+}
+
+#[test]
+fn total_results() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    mock_pages(&server, 3);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let mut page_iter = tmdb.page::<_, u16>(&endpoint);
+
+    assert_eq!(None, page_iter.total_results());
+    page_iter.next();
+    assert_eq!(Some(6), page_iter.total_results());
+    assert_eq!(Some(3), page_iter.total_pages());
+}
+
+#[test]
+fn max_pages() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    let mocks = mock_pages(&server, 5);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let pages: Vec<Page<u16>> = tmdb
+        .page(&endpoint)
+        .max_pages(2)
+        .page_concurrent(4)
+        .pages()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(2, pages.len());
+    for (page, mock) in mocks.iter().enumerate() {
+        mock.assert_hits(if page < 2 { 1 } else { 0 });
+    }
+}
+
+#[test]
+fn max_results() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    let mocks = mock_pages(&server, 5);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let results: Vec<u16> = tmdb
+        .page(&endpoint)
+        .max_results(3)
+        .page_concurrent(4)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    // Three results fit in two pages, so the later pages aren't fetched.
+    assert_eq!(vec![10, 11, 20], results);
+    for (page, mock) in mocks.iter().enumerate() {
+        mock.assert_hits(if page < 2 { 1 } else { 0 });
+    }
+}
+
+#[test]
+fn max_page() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+
+    for page in [499, 500] {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/search/movie")
+                .query_param("page", page.to_string());
+            then.status(200).json_body(json!({
+                "page": page,
+                "results": [page],
+                "total_results": 600,
+                "total_pages": 600,
+            }));
+        });
+    }
+
+    let endpoint = search::Movies::new("Godzilla").page(499);
+    let mut page_iter = tmdb.page(&endpoint).page_concurrent(4);
+    assert!(!page_iter.is_truncated());
+
+    let results: Vec<u16> =
+        page_iter.by_ref().collect::<Result<_, _>>().unwrap();
+
+    // TMDB rejects pages past 500, so the iterator stops there.
+    assert_eq!(vec![499, 500], results);
+    assert!(page_iter.is_truncated());
+}
+
+#[test]
+fn cursor_resume() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    mock_pages(&server, 3);
+
+    let endpoint = search::Movies::new("Godzilla").year(1954);
+    let mut page_iter = tmdb.page::<_, u16>(&endpoint);
+    let results: Vec<u16> = page_iter
+        .by_ref()
+        .take(3)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(vec![10, 11, 20], results);
+
+    let cursor = page_iter.cursor().unwrap();
+    assert_eq!(2, cursor.page());
+    assert_eq!(1, cursor.offset());
+    assert_eq!(
+        vec![("query", "Godzilla"), ("year", "1954"), ("page", "2")],
+        cursor.parameters().iter().collect::<Vec<_>>()
+    );
+
+    // Round trip the cursor as if it were saved before a restart.
+    let saved = serde_json::to_string(&cursor).unwrap();
+    let cursor: Cursor = serde_json::from_str(&saved).unwrap();
+
+    let tmdb = self::tmdb(&server);
+    let results: Vec<u16> = PageIter::resume(&tmdb, &cursor)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(vec![21, 30, 31], results);
+}
+
+#[test]
+fn cursor_end() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    mock_pages(&server, 1);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let mut page_iter = tmdb.page::<_, u16>(&endpoint);
+    assert_eq!(Some(1), page_iter.cursor().map(|cursor| cursor.page()));
+
+    page_iter.by_ref().for_each(drop);
+    assert_eq!(None, page_iter.cursor());
+}
+
+#[test]
+fn owned_page_iter() {
+    let server = MockServer::start();
+    mock_pages(&server, 2);
+
+    let endpoint = search::Movies::new("Godzilla");
+    let page_iter: OwnedPageIter<_, _, u16> =
+        PageIter::owned(tmdb(&server), endpoint);
+
+    let results = thread::spawn(move || {
+        page_iter.collect::<Result<Vec<_>, _>>().unwrap()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(vec![10, 11, 20, 21], results);
+}
+
+#[test]
+fn envelope() {
+    let server = MockServer::start();
+    let tmdb = tmdb(&server);
+    server.mock(|when, then| {
+        when.method(GET).path("/list/8224").query_param("page", "1");
+        then.status(200).json_body(json!({
+            "id": 8224,
+            "items": [11645, 3782],
+            "page": 1,
+            "total_pages": 1,
+        }));
+    });
+
+    let endpoint = list::Details::new(8224);
+    let mut page_iter = tmdb.page::<_, u64>(&endpoint);
+    let results: Vec<u64> =
+        page_iter.by_ref().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(vec![11645, 3782], results);
+    assert_eq!(None, page_iter.total_results());
+}
@@ -0,0 +1,120 @@
+use eiga::{authentication, list, movie, Error, Tmdb, UreqBackend};
+
+const USER_AGENT: &str = concat!("eiga/", env!("CARGO_PKG_VERSION"));
+
+#[test]
+fn prepare() {
+    let mut tmdb = Tmdb::new("secret-token");
+    tmdb.set_session_id("<session_id>");
+    let endpoint = movie::AddRating::new(45706, 8.5);
+
+    let request = tmdb.prepare(&endpoint).unwrap();
+
+    assert_eq!("POST", request.method().as_str());
+    assert_eq!(
+        "https://api.themoviedb.org/3/movie/45706/rating?session_id=%3Csession_id%3E",
+        request.url().as_str()
+    );
+    assert_eq!(
+        vec![
+            ("authorization", "Bearer <redacted>"),
+            ("user-agent", USER_AGENT),
+            ("content-type", "application/json;charset=utf-8"),
+        ],
+        request.headers()
+    );
+    assert_eq!(br#"{"value":8.5}"#, request.body().unwrap().as_bytes());
+    assert_eq!(
+        format!(
+            "curl -X POST 'https://api.themoviedb.org/3/movie/45706/rating?session_id=%3Csession_id%3E' \
+             -H 'authorization: Bearer <redacted>' \
+             -H 'user-agent: {}' \
+             -H 'content-type: application/json;charset=utf-8' \
+             --data-raw '{{\"value\":8.5}}'",
+            USER_AGENT
+        ),
+        request.to_curl()
+    );
+    assert!(!format!("{:?}", request).contains("secret-token"));
+}
+
+#[test]
+fn prepare_redacts_password() {
+    let tmdb = Tmdb::new("<token>");
+    let endpoint = authentication::CreateSessionWithLogin::new(
+        "<username>",
+        "secret-password",
+        "<request_token>",
+    );
+
+    let request = tmdb.prepare(&endpoint).unwrap();
+
+    let curl = request.to_curl();
+    assert!(!curl.contains("secret-password"), "{}", curl);
+    assert!(curl.contains(r#""password":"<redacted>""#), "{}", curl);
+    let debug = format!("{:?}", request);
+    assert!(!debug.contains("secret-password"), "{}", debug);
+    // The body is sent as is.
+    assert!(String::from_utf8_lossy(request.body().unwrap().as_bytes())
+        .contains("secret-password"));
+}
+
+#[test]
+fn prepare_user_agent() {
+    let tmdb = Tmdb::builder("<token>")
+        .user_agent("crawler/1.0")
+        .build()
+        .unwrap();
+
+    let request = tmdb.prepare(&movie::Details::new(45706)).unwrap();
+
+    assert_eq!(
+        vec![
+            ("authorization", "Bearer <redacted>"),
+            ("user-agent", "crawler/1.0"),
+        ],
+        request.headers()
+    );
+}
+
+#[test]
+fn prepare_custom_backend() {
+    // Other backends set their own user agent, so it isn't known.
+    let tmdb = Tmdb::builder("<token>")
+        .backend(UreqBackend::default())
+        .build()
+        .unwrap();
+
+    let request = tmdb.prepare(&movie::Details::new(45706)).unwrap();
+
+    assert_eq!(
+        vec![("authorization", "Bearer <redacted>")],
+        request.headers()
+    );
+}
+
+#[test]
+fn prepare_quotes_curl() {
+    let tmdb = Tmdb::new("<token>");
+    let endpoint = list::Create::new("Ocean's");
+
+    let request = tmdb.prepare(&endpoint).unwrap();
+
+    assert!(
+        request
+            .to_curl()
+            .ends_with(r#"--data-raw '{"name":"Ocean'\''s"}'"#),
+        "unexpected curl command: {}",
+        request.to_curl()
+    );
+}
+
+#[test]
+fn prepare_validates() {
+    let tmdb = Tmdb::new("<token>");
+    let endpoint = movie::AddRating::new(45706, 11.0);
+
+    let result = tmdb.prepare(&endpoint);
+
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
@@ -0,0 +1,14 @@
+use eiga::review;
+
+use crate::TestClient;
+
+#[test]
+fn get_details() {
+    let review_details_endpoint =
+        review::Details::new("5488c29bc3a3686f4a00004a");
+
+    TestClient::new()
+        .method("GET")
+        .path("review/5488c29bc3a3686f4a00004a")
+        .check(review_details_endpoint);
+}
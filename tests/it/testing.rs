@@ -0,0 +1,451 @@
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+use eiga::testing::{Cassette, FakeClient, FixtureServer, Stub};
+use eiga::{authentication, list, movie, search, v4, Client, Error, Tmdb};
+use httpmock::prelude::*;
+use ureq::serde_json::{json, Value};
+
+#[test]
+fn send() {
+    let client = FakeClient::new();
+    client.stub(
+        Stub::get("search/movie")
+            .parameter("query", "Tampopo")
+            .json(json!({ "page": 1 })),
+    );
+
+    let endpoint = search::Movies::new("Tampopo").year(1985);
+    let response: Value = client.send(&endpoint).unwrap();
+
+    assert_eq!(json!({ "page": 1 }), response);
+
+    let requests = client.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method().as_str());
+    assert_eq!("search/movie", requests[0].path());
+    assert_eq!(Some("1985"), requests[0].parameter("year"));
+    assert_eq!(None, requests[0].body());
+}
+
+#[test]
+fn record_body() {
+    let client = FakeClient::new();
+    client.stub(Stub::post("movie/45706/rating"));
+
+    client.ignore(&movie::AddRating::new(45706, 8.5)).unwrap();
+
+    let requests = client.requests();
+    assert_eq!(Some(&br#"{"value":8.5}"#[..]), requests[0].body());
+}
+
+#[test]
+fn most_specific_stub() {
+    let client = FakeClient::new();
+    client.stub(Stub::get("search/movie").json(json!("any")));
+    client.stub(
+        Stub::get("search/movie")
+            .parameter("query", "Tampopo")
+            .json(json!("Tampopo")),
+    );
+
+    let response: Value =
+        client.send(&search::Movies::new("Tampopo")).unwrap();
+    assert_eq!(json!("Tampopo"), response);
+
+    let response: Value = client.send(&search::Movies::new("Ran")).unwrap();
+    assert_eq!(json!("any"), response);
+}
+
+#[test]
+fn status() {
+    let client = FakeClient::new();
+    let expected_message = "The resource you requested could not be found.";
+    client.stub(Stub::get("movie/115572").status(404).json(json!({
+        "status_code": 34,
+        "status_message": expected_message,
+    })));
+
+    let result = client.ignore(&movie::Details::new(115572));
+
+    assert!(
+        matches!(
+            result,
+            Err(Error::Tmdb { code: 404, ref message })
+                if message == expected_message
+        ),
+        "expected result to be `Err(Error::Tmdb {{ code: 404, .. }})`, got:\n{:#?}",
+        result
+    );
+}
+
+#[test]
+fn error() {
+    let client = FakeClient::new();
+    client.stub(
+        Stub::get("movie/115572")
+            .error(|| Error::Validation("injected".to_owned())),
+    );
+
+    let result = client.ignore(&movie::Details::new(115572));
+
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
+
+#[test]
+fn unmatched() {
+    let client = FakeClient::new();
+
+    let result = client.ignore(&movie::Details::new(115572));
+
+    assert!(matches!(result, Err(Error::Tmdb { code: 404, .. })));
+    assert_eq!(1, client.requests().len());
+}
+
+#[test]
+fn page() {
+    let client = FakeClient::new();
+    for page in 1..=3 {
+        client.stub(
+            Stub::get("search/movie")
+                .parameter("page", page.to_string())
+                .json(json!({
+                    "page": page,
+                    "results": [page],
+                    "total_results": 3,
+                    "total_pages": 3,
+                })),
+        );
+    }
+
+    let endpoint = search::Movies::new("Godzilla");
+    let results: Vec<u16> = client
+        .page(&endpoint)
+        .page_concurrent(2)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(vec![1, 2, 3], results);
+    assert_eq!(3, client.requests().len());
+}
+
+/// Returns a path for a cassette file in the temporary directory.
+fn cassette_path(name: &str) -> PathBuf {
+    env::temp_dir()
+        .join(format!("eiga-{}", process::id()))
+        .join(format!("{}.json", name))
+}
+
+#[test]
+fn cassette_record_and_replay() {
+    let path = cassette_path("record_and_replay");
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/movie/45706");
+        then.status(200)
+            .json_body(json!({ "title": "Tokyo Drifter" }));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/movie/115572");
+        then.status(404).json_body(json!({
+            "status_code": 34,
+            "status_message": "The resource you requested could not be found.",
+        }));
+    });
+
+    let tmdb = Tmdb::builder("secret-token")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+    let cassette = Cassette::record(tmdb, &path);
+    let recorded: Value = cassette.send(&movie::Details::new(45706)).unwrap();
+    let recorded_error = cassette.ignore(&movie::Details::new(115572));
+    cassette.save().unwrap();
+    drop(server);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("secret-token"));
+
+    let cassette = Cassette::replay(&path).unwrap();
+    let replayed: Value = cassette.send(&movie::Details::new(45706)).unwrap();
+    let replayed_error = cassette.ignore(&movie::Details::new(115572));
+
+    assert_eq!(recorded, replayed);
+    assert!(matches!(recorded_error, Err(Error::Tmdb { code: 404, .. })));
+    assert!(matches!(replayed_error, Err(Error::Tmdb { code: 404, .. })));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn cassette_scrubs_credentials() {
+    let path = cassette_path("scrubs_credentials");
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/authentication/token/new");
+        then.status(200).json_body(json!({
+            "success": true,
+            "request_token": "secret-request-token",
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/authentication/session/new");
+        then.status(200).json_body(json!({
+            "success": true,
+            "session_id": "secret-session-id",
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/auth/access_token");
+        then.status(200).json_body(json!({
+            "success": true,
+            "access_token": "secret-access-token",
+        }));
+    });
+
+    let tmdb = Tmdb::builder("secret-token")
+        .base_url(server.base_url())
+        .guest_session_id("secret-guest-session-id")
+        .build()
+        .unwrap();
+    let cassette = Cassette::record(tmdb, &path);
+    let token: Value = cassette
+        .send(&authentication::CreateRequestToken::new())
+        .unwrap();
+    let request_token = token["request_token"].as_str().unwrap();
+    cassette
+        .ignore(&authentication::CreateSession::new(request_token))
+        .unwrap();
+    cassette
+        .ignore(&v4::auth::CreateAccessToken::new(request_token))
+        .unwrap();
+    cassette.save().unwrap();
+    drop(server);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("secret"), "{}", contents);
+
+    // The placeholders stand in for the credentials when replaying.
+    let cassette = Cassette::replay(&path).unwrap();
+    let token: Value = cassette
+        .send(&authentication::CreateRequestToken::new())
+        .unwrap();
+    assert_eq!("<request_token>", token["request_token"]);
+    let session: Value = cassette
+        .send(&authentication::CreateSession::new("<request_token>"))
+        .unwrap();
+    assert_eq!("<session_id>", session["session_id"]);
+    cassette
+        .ignore(&v4::auth::CreateAccessToken::new("<request_token>"))
+        .unwrap();
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn cassette_scrubs_login() {
+    let path = cassette_path("scrubs_login");
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/authentication/token/validate_with_login");
+        then.status(200).json_body(json!({
+            "success": true,
+            "request_token": "<request_token>",
+        }));
+    });
+
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+    let cassette = Cassette::record(tmdb, &path);
+    let endpoint = authentication::CreateSessionWithLogin::new(
+        "secret-username",
+        "secret-password",
+        "<request_token>",
+    );
+    cassette.ignore(&endpoint).unwrap();
+    cassette.save().unwrap();
+    drop(server);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("secret"), "{}", contents);
+    assert!(contents.contains("<password>"), "{}", contents);
+
+    // The login still matches its recording when replayed.
+    let cassette = Cassette::replay(&path).unwrap();
+    cassette.ignore(&endpoint).unwrap();
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn cassette_empty_token() {
+    let path = cassette_path("empty_token");
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/movie/45706");
+        then.status(200)
+            .json_body(json!({ "title": "Tokyo Drifter" }));
+    });
+
+    let tmdb = Tmdb::builder("")
+        .base_url(server.base_url())
+        .access_token("")
+        .build()
+        .unwrap();
+    let cassette = Cassette::record(tmdb, &path);
+    cassette.ignore(&movie::Details::new(45706)).unwrap();
+    cassette.save().unwrap();
+    drop(server);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("<token>"), "{}", contents);
+    let cassette = Cassette::replay(&path).unwrap();
+    let replayed: Value = cassette.send(&movie::Details::new(45706)).unwrap();
+    assert_eq!("Tokyo Drifter", replayed["title"]);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "has no response")]
+fn cassette_unmatched() {
+    let path = cassette_path("unmatched");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, r#"{ "interactions": [] }"#).unwrap();
+
+    let cassette = Cassette::replay(&path).unwrap();
+    fs::remove_file(path).unwrap();
+
+    let _ = cassette.ignore(&movie::Details::new(45706));
+}
+
+/// Writes the given fixtures to a new fixture directory and returns its
+/// path.
+fn fixtures(name: &str, fixtures: &[(&str, Value)]) -> PathBuf {
+    let dir = env::temp_dir()
+        .join(format!("eiga-{}", process::id()))
+        .join(name);
+    for (path, fixture) in fixtures {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, fixture.to_string()).unwrap();
+    }
+
+    dir
+}
+
+#[test]
+fn fixture_server() {
+    let dir = fixtures(
+        "fixture_server",
+        &[
+            ("3/movie/45706.json", json!({ "title": "Tokyo Drifter" })),
+            (
+                "3/movie/45706/rating.post.json",
+                json!({ "status_code": 1 }),
+            ),
+            (
+                "3/movie/3.json",
+                json!({
+                    "success": false,
+                    "status_code": 25,
+                    "status_message": "Your request count (#) is over the allowed limit of (40).",
+                }),
+            ),
+            (
+                "3/movie/4.json",
+                json!({
+                    "success": false,
+                    "status_code": 11,
+                    "status_message": "Internal error: Something went wrong, contact TMDb.",
+                }),
+            ),
+            (
+                "3/list/8224.delete.json",
+                json!({
+                    "success": false,
+                    "status_code": 11,
+                    "status_message": "Internal error: Something went wrong, contact TMDb.",
+                }),
+            ),
+        ],
+    );
+    let server = FixtureServer::new(&dir)
+        .token("<token>")
+        .start("127.0.0.1:0")
+        .unwrap();
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let details: Value = tmdb.send(&movie::Details::new(45706)).unwrap();
+    assert_eq!(json!({ "title": "Tokyo Drifter" }), details);
+
+    tmdb.ignore(&movie::AddRating::new(45706, 8.5)).unwrap();
+
+    let result = tmdb.ignore(&movie::Details::new(3));
+    assert!(matches!(result, Err(Error::Tmdb { code: 429, .. })));
+
+    let result = tmdb.ignore(&movie::Details::new(4));
+    assert!(matches!(result, Err(Error::Tmdb { code: 500, .. })));
+
+    // TMDB responds to list deletions with an internal error.
+    tmdb.ignore(&list::Delete::new(8224)).unwrap();
+
+    let result = tmdb.ignore(&movie::Details::new(115572));
+    assert!(matches!(result, Err(Error::Tmdb { code: 404, .. })));
+
+    let tmdb = Tmdb::builder("<wrong-token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+    let result = tmdb.ignore(&movie::Details::new(45706));
+    assert!(matches!(result, Err(Error::Tmdb { code: 401, .. })));
+}
+
+#[test]
+fn fixture_server_page() {
+    let results: Vec<u16> = (0..45).collect();
+    let dir = fixtures(
+        "fixture_server_page",
+        &[("3/search/movie.json", json!(results))],
+    );
+    let server = FixtureServer::new(&dir).start("127.0.0.1:0").unwrap();
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let endpoint = search::Movies::new("Godzilla");
+    let mut page_iter = tmdb.page::<_, u16>(&endpoint);
+    let paged: Vec<u16> =
+        page_iter.by_ref().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(results, paged);
+    assert_eq!(Some(3), page_iter.total_pages());
+}
+
+#[test]
+fn fixture_server_rate_limit() {
+    let dir = fixtures(
+        "fixture_server_rate_limit",
+        &[("3/movie/45706.json", json!({}))],
+    );
+    let server = FixtureServer::new(&dir)
+        .rate_limit(1)
+        .start("127.0.0.1:0")
+        .unwrap();
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+    let result = tmdb.ignore(&movie::Details::new(45706));
+
+    assert!(matches!(result, Err(Error::Tmdb { code: 429, .. })));
+}
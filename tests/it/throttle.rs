@@ -0,0 +1,181 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use eiga::{
+    movie, Client, Error, HttpBackend, HttpResponse, ImageUrlBuilder,
+    PosterSize, Tmdb,
+};
+use http::Method;
+use ureq::serde_json::{self, json};
+use url::Url;
+
+/// A backend that responds with the given statuses in order, then with 200.
+#[derive(Debug, Default)]
+struct SequenceBackend {
+    statuses: Mutex<Vec<u16>>,
+    requests: Arc<Mutex<usize>>,
+}
+
+impl SequenceBackend {
+    fn new(statuses: &[u16], requests: &Arc<Mutex<usize>>) -> SequenceBackend {
+        let mut statuses = statuses.to_vec();
+        statuses.reverse();
+
+        SequenceBackend {
+            statuses: Mutex::new(statuses),
+            requests: Arc::clone(requests),
+        }
+    }
+}
+
+impl HttpBackend for SequenceBackend {
+    fn send(
+        &self,
+        _method: &Method,
+        _url: &Url,
+        _headers: &[(&str, &str)],
+        _body: Option<&[u8]>,
+    ) -> Result<HttpResponse, Error> {
+        *self.requests.lock().unwrap() += 1;
+        let status = self.statuses.lock().unwrap().pop().unwrap_or(200);
+
+        Ok(HttpResponse::new(
+            status,
+            vec![("Retry-After".to_owned(), "0".to_owned())],
+            br#"{"status_code":25,"status_message":"Slow down."}"#.to_vec(),
+        ))
+    }
+}
+
+#[test]
+fn retry_too_many_requests() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[429, 429], &requests))
+        .retry(2)
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    assert_eq!(3, *requests.lock().unwrap());
+}
+
+#[test]
+fn retry_runs_out() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[429, 429, 429], &requests))
+        .retry(1)
+        .build()
+        .unwrap();
+
+    let result = tmdb.ignore(&movie::Details::new(45706));
+
+    assert!(matches!(result, Err(Error::Tmdb { code: 429, .. })));
+    assert_eq!(2, *requests.lock().unwrap());
+}
+
+#[test]
+fn no_retry_by_default() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[503], &requests))
+        .build()
+        .unwrap();
+
+    let result = tmdb.ignore(&movie::Details::new(45706));
+
+    assert!(matches!(result, Err(Error::Tmdb { code: 503, .. })));
+    assert_eq!(1, *requests.lock().unwrap());
+}
+
+#[test]
+fn retry_server_error() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[500, 503], &requests))
+        .retry(3)
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    assert_eq!(3, *requests.lock().unwrap());
+}
+
+#[test]
+fn no_retry_server_error_post() {
+    // A POST request may have changed something before the server failed.
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[500], &requests))
+        .session_id("<session_id>")
+        .retry(3)
+        .build()
+        .unwrap();
+
+    let result = tmdb.ignore(&movie::AddRating::new(45706, 8.5));
+
+    assert!(matches!(result, Err(Error::Tmdb { code: 500, .. })));
+    assert_eq!(1, *requests.lock().unwrap());
+}
+
+#[test]
+fn retry_image_download() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[503], &requests))
+        .retry(1)
+        .build()
+        .unwrap();
+    let image_url_builder: ImageUrlBuilder = serde_json::from_value(json!({
+        "images": {
+            "secure_base_url": "https://image.tmdb.org/t/p/",
+            "backdrop_sizes": [],
+            "logo_sizes": [],
+            "poster_sizes": ["w500"],
+            "profile_sizes": [],
+            "still_sizes": []
+        }
+    }))
+    .unwrap();
+
+    let mut image = Vec::new();
+    tmdb.download_image(
+        &image_url_builder,
+        "/poster.jpg",
+        PosterSize::W500,
+        &mut image,
+    )
+    .unwrap();
+
+    assert_eq!(2, *requests.lock().unwrap());
+}
+
+#[test]
+fn rate_limit() {
+    let requests = Arc::default();
+    let tmdb = Tmdb::builder("<token>")
+        .backend(SequenceBackend::new(&[], &requests))
+        .rate_limit(20)
+        .build()
+        .unwrap();
+    // Clones share the rate limit.
+    let clone = tmdb.clone();
+
+    let start = Instant::now();
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+    clone.ignore(&movie::Details::new(45706)).unwrap();
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(3, *requests.lock().unwrap());
+}
+
+#[test]
+fn rate_limit_zero() {
+    let result = Tmdb::builder("<token>").rate_limit(0).build();
+
+    assert!(matches!(result, Err(Error::Config(_))));
+}
@@ -0,0 +1,120 @@
+use std::thread;
+
+use eiga::{
+    movie, search, Client, Country, Cursor, Language, Tmdb, TmdbBuilder,
+};
+use httpmock::prelude::*;
+use ureq::serde_json::{self, Value};
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Tmdb>();
+    assert_send_sync::<TmdbBuilder>();
+}
+
+#[test]
+fn clone() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.path("/movie/45706")
+            .header("authorization", "Bearer <token>");
+        then.status(200);
+    });
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let tmdb = tmdb.clone();
+            thread::spawn(move || {
+                tmdb.ignore(&movie::Details::new(45706)).is_ok()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+
+    mock.assert_hits(4);
+}
+
+#[test]
+fn owned_builder() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.path("/movie/45706");
+        then.status(200);
+    });
+    let mut builder = Tmdb::builder("<token>");
+    {
+        let base_url = server.base_url();
+        builder.base_url(base_url);
+    }
+    let tmdb = builder.build().unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn defaults() {
+    let tmdb = Tmdb::builder("<token>")
+        .language(Language::En)
+        .region(Country::Us)
+        .include_adult(false)
+        .watch_region(Country::Us)
+        .build()
+        .unwrap();
+    let endpoint = search::Movies::new("Tampopo").region(Country::Jp);
+
+    let request = tmdb.prepare(&endpoint).unwrap();
+
+    // The endpoint's region wins, and search movies has no watch region.
+    assert_eq!(
+        "https://api.themoviedb.org/3/search/movie?query=Tampopo&region=JP\
+         &language=en&include_adult=false",
+        request.url().as_str()
+    );
+}
+
+#[test]
+fn defaults_unsupported() {
+    let tmdb = Tmdb::builder("<token>")
+        .language(Language::En)
+        .build()
+        .unwrap();
+
+    let request = tmdb.prepare(&movie::AlternativeTitles::new(45706)).unwrap();
+
+    assert_eq!(
+        "https://api.themoviedb.org/3/movie/45706/alternative_titles",
+        request.url().as_str()
+    );
+}
+
+#[test]
+fn defaults_cursor() {
+    let tmdb = Tmdb::builder("<token>")
+        .language(Language::Ja)
+        .build()
+        .unwrap();
+    let endpoint = search::Movies::new("Tampopo");
+    let page_iter = tmdb.page::<_, Value>(&endpoint);
+    let cursor = page_iter.cursor().unwrap();
+
+    let cursor: Cursor =
+        serde_json::from_str(&serde_json::to_string(&cursor).unwrap())
+            .unwrap();
+    let request = tmdb.prepare(&cursor).unwrap();
+
+    assert_eq!(
+        "https://api.themoviedb.org/3/search/movie?query=Tampopo&page=1\
+         &language=ja",
+        request.url().as_str()
+    );
+}
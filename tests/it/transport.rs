@@ -0,0 +1,200 @@
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use eiga::{movie, Client, Error, HttpBackend, HttpResponse, Tmdb};
+use http::Method;
+use httpmock::prelude::*;
+use ureq::serde_json::Value;
+use url::Url;
+
+type Requests = Arc<Mutex<Vec<(String, String, Vec<(String, String)>)>>>;
+
+/// A backend that records requests and responds with a canned response.
+#[derive(Debug)]
+struct CannedBackend {
+    status: u16,
+    body: &'static str,
+    requests: Requests,
+}
+
+impl HttpBackend for CannedBackend {
+    fn send(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        _body: Option<&[u8]>,
+    ) -> Result<HttpResponse, Error> {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        self.requests.lock().unwrap().push((
+            method.to_string(),
+            url.to_string(),
+            headers,
+        ));
+
+        Ok(HttpResponse::new(
+            self.status,
+            Vec::new(),
+            self.body.as_bytes().to_vec(),
+        ))
+    }
+}
+
+#[test]
+fn default_user_agent() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.header("user-agent", concat!("eiga/", env!("CARGO_PKG_VERSION")));
+        then.status(200);
+    });
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn user_agent() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.header("user-agent", "crawler/1.0");
+        then.status(200);
+    });
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .user_agent("crawler/1.0")
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn agent() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.header("user-agent", "prebuilt/1.0");
+        then.status(200);
+    });
+    let agent = ureq::AgentBuilder::new().user_agent("prebuilt/1.0").build();
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .user_agent("ignored/1.0")
+        .agent(agent)
+        .build()
+        .unwrap();
+
+    tmdb.ignore(&movie::Details::new(45706)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn timeout() {
+    let server = MockServer::start();
+    server.mock(|_, then| {
+        then.status(200).delay(Duration::from_secs(2));
+    });
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(server.base_url())
+        .timeout_read(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let err = tmdb.ignore(&movie::Details::new(45706)).unwrap_err();
+
+    assert!(matches!(err, Error::Transport(_)));
+    assert!(err
+        .to_string()
+        .starts_with("failed to make the request or receive an response: "));
+}
+
+#[test]
+fn invalid_proxy() {
+    let result = Tmdb::builder("<token>").proxy("ftp://localhost:21").build();
+
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[test]
+fn invalid_root_certificate() {
+    let result = Tmdb::builder("<token>")
+        .root_certificate(b"not a certificate".to_vec())
+        .build();
+
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[test]
+fn backend() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let tmdb = Tmdb::builder("<token>")
+        .backend(CannedBackend {
+            status: 200,
+            body: r#"{"title":"Tokyo Drifter"}"#,
+            requests: Arc::clone(&requests),
+        })
+        .build()
+        .unwrap();
+
+    let details: Value = tmdb.send(&movie::Details::new(45706)).unwrap();
+
+    assert_eq!("Tokyo Drifter", details["title"]);
+    assert_eq!(
+        vec![(
+            "GET".to_owned(),
+            "https://api.themoviedb.org/3/movie/45706".to_owned(),
+            vec![("authorization".to_owned(), "Bearer <token>".to_owned())],
+        )],
+        *requests.lock().unwrap()
+    );
+}
+
+#[test]
+fn backend_error() {
+    let tmdb = Tmdb::builder("<token>")
+        .backend(CannedBackend {
+            status: 404,
+            body: r#"{"status_code":34,"status_message":"Not found."}"#,
+            requests: Arc::default(),
+        })
+        .build()
+        .unwrap();
+
+    let result = tmdb.ignore(&movie::Details::new(45706));
+
+    assert!(matches!(
+        result,
+        Err(Error::Tmdb { code: 404, message }) if message == "Not found."
+    ));
+}
+
+#[test]
+fn backend_streaming() {
+    let backend = CannedBackend {
+        status: 200,
+        body: "<poster>",
+        requests: Arc::default(),
+    };
+    let url =
+        Url::parse("https://image.tmdb.org/t/p/w500/poster.jpg").unwrap();
+
+    let response = backend
+        .send_streaming(&Method::GET, &url, &[], None)
+        .unwrap();
+
+    assert_eq!(200, response.status());
+    let mut body = String::new();
+    response.into_body().read_to_string(&mut body).unwrap();
+    assert_eq!("<poster>", body);
+}
@@ -0,0 +1,56 @@
+use eiga::tv;
+use ureq::serde_json::json;
+
+use crate::TestClient;
+
+#[test]
+fn add_rating() {
+    let tv_add_rating_endpoint = tv::AddRating::new(1399, 10.0);
+
+    TestClient::new()
+        .method("POST")
+        .path("tv/1399/rating")
+        .parameters(&[("session_id", "<session_id>")])
+        .body(json!({ "value": 10.0 }))
+        .session_id("<session_id>")
+        .check(tv_add_rating_endpoint);
+}
+
+#[test]
+fn delete_rating() {
+    let tv_delete_rating_endpoint = tv::DeleteRating::new(1399);
+
+    TestClient::new()
+        .method("DELETE")
+        .path("tv/1399/rating")
+        .parameters(&[("session_id", "<session_id>")])
+        .session_id("<session_id>")
+        .check(tv_delete_rating_endpoint);
+}
+
+#[test]
+fn add_episode_rating() {
+    let tv_episode_add_rating_endpoint =
+        tv::episode::AddRating::new(1399, 1, 1, 0.5);
+
+    TestClient::new()
+        .method("POST")
+        .path("tv/1399/season/1/episode/1/rating")
+        .parameters(&[("guest_session_id", "<guest_session_id>")])
+        .body(json!({ "value": 0.5 }))
+        .guest_session_id("<guest_session_id>")
+        .check(tv_episode_add_rating_endpoint);
+}
+
+#[test]
+fn delete_episode_rating() {
+    let tv_episode_delete_rating_endpoint =
+        tv::episode::DeleteRating::new(1399, 1, 1);
+
+    TestClient::new()
+        .method("DELETE")
+        .path("tv/1399/season/1/episode/1/rating")
+        .parameters(&[("guest_session_id", "<guest_session_id>")])
+        .guest_session_id("<guest_session_id>")
+        .check(tv_episode_delete_rating_endpoint);
+}
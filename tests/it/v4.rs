@@ -0,0 +1,239 @@
+use eiga::account::MediaType;
+use eiga::{v4, Client, Language, Tmdb};
+use httpmock::prelude::*;
+use ureq::serde_json::json;
+
+use crate::TestClient;
+
+#[test]
+fn route_to_v4_base_url() {
+    let server = MockServer::start();
+    let tmdb = Tmdb::builder("<token>")
+        .base_url(format!("{}/3/", server.base_url()))
+        .build()
+        .unwrap();
+
+    let v4_mock = server.mock(|when, then| {
+        when.method(GET).path("/4/list/1");
+        then.status(200);
+    });
+    let v3_mock = server.mock(|when, then| {
+        when.method(GET).path("/3/configuration");
+        then.status(200);
+    });
+
+    tmdb.ignore(&v4::list::Details::new(1)).unwrap();
+    tmdb.ignore(&eiga::configuration::ApiConfiguration::new())
+        .unwrap();
+
+    v4_mock.assert();
+    v3_mock.assert();
+}
+
+#[test]
+fn create_request_token() {
+    let create_request_token_endpoint =
+        v4::auth::CreateRequestToken::new().redirect_to("http://localhost");
+
+    TestClient::new()
+        .method("POST")
+        .path("auth/request_token")
+        .body(json!({ "redirect_to": "http://localhost" }))
+        .check(create_request_token_endpoint);
+}
+
+#[test]
+fn create_access_token() {
+    let create_access_token_endpoint =
+        v4::auth::CreateAccessToken::new("<request_token>");
+
+    TestClient::new()
+        .method("POST")
+        .path("auth/access_token")
+        .body(json!({ "request_token": "<request_token>" }))
+        .check(create_access_token_endpoint);
+}
+
+#[test]
+fn delete_access_token() {
+    let delete_access_token_endpoint =
+        v4::auth::DeleteAccessToken::new("<access_token>");
+
+    TestClient::new()
+        .method("DELETE")
+        .path("auth/access_token")
+        .body(json!({ "access_token": "<access_token>" }))
+        .check(delete_access_token_endpoint);
+}
+
+#[test]
+fn get_list_details() {
+    let list_details_endpoint = v4::list::Details::new(1)
+        .language(Language::En)
+        .page(1)
+        .sort_by(v4::list::SortBy::TitleAsc);
+
+    TestClient::new()
+        .method("GET")
+        .path("list/1")
+        .parameters(&[
+            ("language", "en"),
+            ("page", "1"),
+            ("sort_by", "title.asc"),
+        ])
+        .access_token("<access_token>")
+        .check(list_details_endpoint);
+}
+
+#[test]
+fn create_list() {
+    let create_list_endpoint = v4::list::Create::new("Kurosawa", Language::En)
+        .description("Films by Akira Kurosawa")
+        .public(false);
+
+    TestClient::new()
+        .method("POST")
+        .path("list")
+        .body(json!({
+            "name": "Kurosawa",
+            "iso_639_1": "en",
+            "description": "Films by Akira Kurosawa",
+            "public": false,
+        }))
+        .access_token("<access_token>")
+        .check(create_list_endpoint);
+}
+
+#[test]
+fn update_list() {
+    let update_list_endpoint =
+        v4::list::Update::new(1).name("Kurosawa (updated)");
+
+    TestClient::new()
+        .method("PUT")
+        .path("list/1")
+        .body(json!({ "name": "Kurosawa (updated)" }))
+        .access_token("<access_token>")
+        .check(update_list_endpoint);
+}
+
+#[test]
+fn delete_list() {
+    let delete_list_endpoint = v4::list::Delete::new(1);
+
+    TestClient::new()
+        .method("DELETE")
+        .path("list/1")
+        .access_token("<access_token>")
+        .check(delete_list_endpoint);
+}
+
+#[test]
+fn clear_list() {
+    let clear_list_endpoint = v4::list::Clear::new(1);
+
+    TestClient::new()
+        .method("GET")
+        .path("list/1/clear")
+        .access_token("<access_token>")
+        .check(clear_list_endpoint);
+}
+
+#[test]
+fn add_list_items() {
+    let add_items_endpoint = v4::list::AddItems::new(
+        1,
+        vec![
+            v4::list::Item::new(MediaType::Movie, 11645),
+            v4::list::Item::new(MediaType::Tv, 1399),
+        ],
+    );
+
+    TestClient::new()
+        .method("POST")
+        .path("list/1/items")
+        .body(json!({
+            "items": [
+                { "media_type": "movie", "media_id": 11645 },
+                { "media_type": "tv", "media_id": 1399 },
+            ]
+        }))
+        .access_token("<access_token>")
+        .check(add_items_endpoint);
+}
+
+#[test]
+fn update_list_items() {
+    let update_items_endpoint = v4::list::UpdateItems::new(
+        1,
+        vec![v4::list::Item::new(MediaType::Movie, 11645).comment("Ran")],
+    );
+
+    TestClient::new()
+        .method("PUT")
+        .path("list/1/items")
+        .body(json!({
+            "items": [
+                { "media_type": "movie", "media_id": 11645, "comment": "Ran" },
+            ]
+        }))
+        .access_token("<access_token>")
+        .check(update_items_endpoint);
+}
+
+#[test]
+fn remove_list_items() {
+    let remove_items_endpoint = v4::list::RemoveItems::new(
+        1,
+        vec![v4::list::Item::new(MediaType::Movie, 11645)],
+    );
+
+    TestClient::new()
+        .method("DELETE")
+        .path("list/1/items")
+        .body(json!({
+            "items": [{ "media_type": "movie", "media_id": 11645 }]
+        }))
+        .access_token("<access_token>")
+        .check(remove_items_endpoint);
+}
+
+#[test]
+fn get_list_item_status() {
+    let item_status_endpoint =
+        v4::list::ItemStatus::new(1, MediaType::Movie, 11645);
+
+    TestClient::new()
+        .method("GET")
+        .path("list/1/item_status")
+        .parameters(&[("media_id", "11645"), ("media_type", "movie")])
+        .access_token("<access_token>")
+        .check(item_status_endpoint);
+}
+
+#[test]
+fn get_account_lists() {
+    let account_lists_endpoint =
+        v4::account::Lists::new("4bc8892a017a3c0f92000002").page(2);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/4bc8892a017a3c0f92000002/lists")
+        .parameters(&[("page", "2")])
+        .access_token("<access_token>")
+        .check(account_lists_endpoint);
+}
+
+#[test]
+fn get_account_movie_watchlist() {
+    let account_movie_watchlist_endpoint =
+        v4::account::MovieWatchlist::new("4bc8892a017a3c0f92000002")
+            .sort_by(eiga::account::SortBy::CreatedAtDesc);
+
+    TestClient::new()
+        .method("GET")
+        .path("account/4bc8892a017a3c0f92000002/movie/watchlist")
+        .parameters(&[("sort_by", "created_at.desc")])
+        .access_token("<access_token>")
+        .check(account_movie_watchlist_endpoint);
+}